@@ -0,0 +1,332 @@
+//! Size of the generated rules per tool, checked against the tool's limits.
+//!
+//! Every rule a tool receives costs characters in its rule files, and
+//! always-on rules cost context in every request. Sizes are measured on the
+//! converted output, frontmatter included, since that is what the tool reads.
+//! Tokens are approximated as one per four characters.
+
+use crate::models::{LimitPolicy, ToolLimits};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::fmt;
+
+/// Approximate token count of `text`, at about four characters per token
+#[must_use]
+pub fn approximate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Size of one generated rule file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleSize {
+    pub rule: String,
+    pub chars: usize,
+    pub tokens: usize,
+    /// The tool loads the rule into every request
+    pub always_on: bool,
+}
+
+/// Every rule generated for one tool, with the limits that apply to it
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolBudget {
+    pub tool: String,
+    pub limits: ToolLimits,
+    pub rules: Vec<RuleSize>,
+}
+
+impl ToolBudget {
+    /// Characters across every generated rule
+    #[must_use]
+    pub fn total_chars(&self) -> usize {
+        self.rules.iter().map(|rule| rule.chars).sum()
+    }
+
+    /// Rules loaded into every request
+    pub fn always_on(&self) -> impl Iterator<Item = &RuleSize> {
+        self.rules.iter().filter(|rule| rule.always_on)
+    }
+
+    /// Approximate tokens across always-on rules
+    #[must_use]
+    pub fn always_on_tokens(&self) -> usize {
+        self.always_on().map(|rule| rule.tokens).sum()
+    }
+
+    /// Limits the generated rules exceed
+    #[must_use]
+    pub fn violations(&self) -> Vec<LimitViolation> {
+        let violation = |rule: Option<&str>, limit, actual, max| LimitViolation {
+            tool: self.tool.clone(),
+            rule: rule.map(str::to_string),
+            limit,
+            actual,
+            max,
+            policy: self.limits.on_exceed,
+        };
+        let mut violations = Vec::new();
+
+        if let Some(max) = self.limits.max_file_chars {
+            violations.extend(
+                self.rules
+                    .iter()
+                    .filter(|rule| rule.chars > max)
+                    .map(|rule| violation(Some(&rule.rule), Limit::FileChars, rule.chars, max)),
+            );
+        }
+        if let Some(max) = self
+            .limits
+            .max_total_chars
+            .filter(|&max| self.total_chars() > max)
+        {
+            violations.push(violation(None, Limit::TotalChars, self.total_chars(), max));
+        }
+        if let Some(max) = self
+            .limits
+            .max_always_on_tokens
+            .filter(|&max| self.always_on_tokens() > max)
+        {
+            violations.push(violation(
+                None,
+                Limit::AlwaysOnTokens,
+                self.always_on_tokens(),
+                max,
+            ));
+        }
+
+        violations
+    }
+}
+
+/// Which limit a tool's rules exceed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Limit {
+    /// `maxFileChars`
+    FileChars,
+    /// `maxTotalChars`
+    TotalChars,
+    /// `maxAlwaysOnTokens`
+    AlwaysOnTokens,
+}
+
+/// A limit exceeded by the rules generated for a tool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LimitViolation {
+    pub tool: String,
+    /// The oversized rule, for per-file limits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    pub limit: Limit,
+    pub actual: usize,
+    pub max: usize,
+    pub policy: LimitPolicy,
+}
+
+impl fmt::Display for LimitViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            tool, actual, max, ..
+        } = self;
+        match (self.limit, &self.rule) {
+            (Limit::FileChars, Some(rule)) => write!(
+                f,
+                "{rule} ({tool}) is {actual} characters, over the {max}-character limit per rule file"
+            ),
+            (Limit::AlwaysOnTokens, _) => write!(
+                f,
+                "{tool} always-on rules total ~{actual} tokens, over the {max}-token budget"
+            ),
+            _ => write!(
+                f,
+                "{tool} rules total {actual} characters, over the {max}-character limit across rules"
+            ),
+        }
+    }
+}
+
+/// Sizes of the rules generated for every tool
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BudgetReport {
+    pub tools: Vec<ToolBudget>,
+}
+
+impl BudgetReport {
+    /// Record the converted `content` of `rule` for `tool`
+    pub fn record(
+        &mut self,
+        tool: &str,
+        limits: &ToolLimits,
+        rule: &str,
+        content: &str,
+        always_on: bool,
+    ) -> &RuleSize {
+        let index = if let Some(index) = self.tools.iter().position(|budget| budget.tool == tool) {
+            index
+        } else {
+            self.tools.push(ToolBudget {
+                tool: tool.to_string(),
+                limits: *limits,
+                rules: Vec::new(),
+            });
+            self.tools.len() - 1
+        };
+
+        let rules = &mut self.tools[index].rules;
+        rules.push(RuleSize {
+            rule: rule.to_string(),
+            chars: content.chars().count(),
+            tokens: approximate_tokens(content),
+            always_on,
+        });
+        &rules[rules.len() - 1]
+    }
+
+    /// Every limit exceeded, tool by tool
+    #[must_use]
+    pub fn violations(&self) -> Vec<LimitViolation> {
+        self.tools.iter().flat_map(ToolBudget::violations).collect()
+    }
+
+    /// Exceeded limits whose tool fails on them
+    #[must_use]
+    pub fn failures(&self) -> usize {
+        self.violations()
+            .iter()
+            .filter(|violation| violation.policy == LimitPolicy::Fail)
+            .count()
+    }
+
+    /// Tools whose rules together exceed `maxTotalChars` or `maxAlwaysOnTokens`
+    /// under a `fail` policy
+    #[must_use]
+    pub fn failing_totals(&self) -> Vec<String> {
+        let mut tools: Vec<String> = self
+            .violations()
+            .into_iter()
+            .filter(|violation| violation.policy == LimitPolicy::Fail && violation.rule.is_none())
+            .map(|violation| violation.tool)
+            .collect();
+        tools.dedup();
+        tools
+    }
+
+    /// Print the always-on budget of each tool, then any exceeded limits
+    #[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
+    pub fn print(&self, prefix: &str) {
+        if self.tools.is_empty() {
+            return;
+        }
+
+        println!("\n{prefix}Context budget:");
+        let width = self
+            .tools
+            .iter()
+            .map(|budget| budget.tool.len())
+            .max()
+            .unwrap_or_default();
+        let of = |max: Option<usize>| max.map(|max| format!(" of {max}")).unwrap_or_default();
+        for budget in &self.tools {
+            println!(
+                "  {:<width$}  {} always-on rule(s), ~{}{} tokens; {} rule(s), {}{} characters in total",
+                budget.tool,
+                budget.always_on().count(),
+                budget.always_on_tokens(),
+                of(budget.limits.max_always_on_tokens),
+                budget.rules.len(),
+                budget.total_chars(),
+                of(budget.limits.max_total_chars)
+            );
+        }
+
+        let violations = self.violations();
+        if violations.is_empty() {
+            return;
+        }
+        println!("\n{prefix}⚠ Size limits exceeded ({}):", violations.len());
+        for violation in &violations {
+            match violation.policy {
+                LimitPolicy::Warn | LimitPolicy::Split => println!("  ~ {violation}"),
+                LimitPolicy::Fail => println!("  ! {}", format!("{violation} (fails sync)").red()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(file: usize, total: usize, tokens: usize) -> ToolLimits {
+        ToolLimits {
+            max_file_chars: Some(file),
+            max_total_chars: Some(total),
+            max_always_on_tokens: Some(tokens),
+            on_exceed: LimitPolicy::Warn,
+        }
+    }
+
+    #[test]
+    fn test_approximate_tokens() {
+        assert_eq!(approximate_tokens(""), 0);
+        assert_eq!(approximate_tokens("abcd"), 1);
+        assert_eq!(approximate_tokens("abcde"), 2);
+        // Characters, not bytes
+        assert_eq!(approximate_tokens("ééééé"), 2);
+    }
+
+    #[test]
+    fn test_budget_within_limits() {
+        let mut report = BudgetReport::default();
+        let limits = limits(100, 200, 50);
+        report.record("windsurf", &limits, "core", &"a".repeat(80), true);
+        report.record("windsurf", &limits, "python", &"b".repeat(40), false);
+        report.record(
+            "cursor",
+            &ToolLimits::default(),
+            "core",
+            &"a".repeat(90),
+            true,
+        );
+
+        let windsurf = &report.tools[0];
+        assert_eq!(windsurf.total_chars(), 120);
+        assert_eq!(windsurf.always_on_tokens(), 20);
+        assert_eq!(windsurf.always_on().count(), 1);
+        assert!(report.violations().is_empty());
+        assert_eq!(report.tools[1].tool, "cursor");
+    }
+
+    #[test]
+    fn test_budget_violations() {
+        let mut report = BudgetReport::default();
+        let limits = ToolLimits {
+            on_exceed: LimitPolicy::Fail,
+            ..limits(100, 150, 30)
+        };
+        report.record("windsurf", &limits, "core", &"a".repeat(140), true);
+        report.record("windsurf", &limits, "python", &"b".repeat(40), false);
+
+        let violations = report.violations();
+        assert_eq!(
+            violations
+                .iter()
+                .map(|violation| (violation.limit, violation.rule.as_deref(), violation.actual))
+                .collect::<Vec<_>>(),
+            [
+                (Limit::FileChars, Some("core"), 140),
+                (Limit::TotalChars, None, 180),
+                (Limit::AlwaysOnTokens, None, 35),
+            ]
+        );
+        assert_eq!(report.failures(), 3);
+        assert_eq!(report.failing_totals(), ["windsurf"]);
+        assert_eq!(
+            violations[0].to_string(),
+            "core (windsurf) is 140 characters, over the 100-character limit per rule file"
+        );
+        assert_eq!(
+            violations[2].to_string(),
+            "windsurf always-on rules total ~35 tokens, over the 30-token budget"
+        );
+    }
+}
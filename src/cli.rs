@@ -1,6 +1,7 @@
 //! CLI definitions using clap derive macros.
 
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 /// AgentSync CLI application
 #[derive(Parser, Debug)]
@@ -17,6 +18,14 @@ pub struct Cli {
     /// Enable verbose logging
     #[arg(short, long, global = true)]
     pub verbose: bool,
+
+    /// Treat frontmatter warnings (unknown keys, invalid values) as errors
+    #[arg(long, global = true)]
+    pub strict: bool,
+
+    /// Path to agentsync.json; the project root is the directory containing it
+    #[arg(long, global = true, value_name = "PATH", env = "AGENTSYNC_CONFIG")]
+    pub config: Option<PathBuf>,
 }
 
 /// Available CLI commands
@@ -24,7 +33,27 @@ pub struct Cli {
 pub enum Commands {
     /// Initialize AgentSync in the current project
     #[command(about = "Create .agentsync/ directory and configuration")]
-    Init,
+    Init {
+        /// Import existing rules from a tool, or `all` to merge every tool's rules
+        #[arg(long, value_name = "TOOL|all", conflicts_with = "no_import")]
+        import: Option<String>,
+
+        /// Don't import existing rules
+        #[arg(long)]
+        no_import: bool,
+
+        /// Comma-separated tools to enable in agentsync.json (default: all)
+        #[arg(long, value_name = "TOOLS", value_delimiter = ',')]
+        tools: Vec<String>,
+
+        /// Create detected starter rules without asking: comma-separated names, or `all`
+        #[arg(long, value_name = "RULES", value_delimiter = ',')]
+        scaffold: Vec<String>,
+
+        /// Don't prompt; use defaults (no import or starter rules unless asked for)
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
 
     /// Sync rules between AgentSync and tools
     #[command(
@@ -46,6 +75,193 @@ pub enum Commands {
         /// Name of the rule (kebab-case recommended)
         #[arg(value_name = "RULE_NAME")]
         name: String,
+
+        /// Description agents use to decide when the rule applies
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Comma-separated glob patterns (implies --mode glob)
+        #[arg(long, value_name = "GLOBS")]
+        globs: Option<String>,
+
+        /// Comma-separated tools to sync to (default: all)
+        #[arg(long, value_name = "TOOLS", value_delimiter = ',')]
+        targets: Vec<String>,
+
+        /// When tools load the rule
+        #[arg(long, value_enum)]
+        mode: Option<crate::models::Activation>,
+
+        /// Read the rule body from a file, or `-` for stdin
+        #[arg(long, value_name = "PATH")]
+        from_file: Option<PathBuf>,
+
+        /// Open the new rule in $VISUAL or $EDITOR
+        #[arg(long)]
+        edit: bool,
+
+        /// Start from a template (see `agentsync templates`); other flags override it
+        #[arg(long, short = 't', value_name = "TEMPLATE")]
+        template: Option<String>,
+    },
+
+    /// List rule templates for `agentsync add --template`
+    #[command(
+        about = "List built-in rule templates and the project's own in .agentsync/templates/"
+    )]
+    Templates {
+        /// Print templates as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check configuration and rules for problems
+    #[command(
+        about = "Validate agentsync.json and every rule in .agentsync/rules/ (or a tool's rules with --tool)"
+    )]
+    Validate {
+        /// Validate a tool's rule files instead of .agentsync/rules/
+        #[arg(long, value_name = "TOOL")]
+        tool: Option<String>,
+
+        /// Also warn about globs that match no files in the working tree, and
+        /// rules that match exactly the same files
+        #[arg(long, conflicts_with = "tool")]
+        globs: bool,
+    },
+
+    /// Show which rules are out of sync with which tools
+    #[command(about = "Show the sync state of every rule in every enabled tool")]
+    Status {
+        /// Print the status as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List rules with their targets, activation modes, and globs
+    #[command(about = "List every rule in .agentsync/rules/ with how each tool activates it")]
+    List {
+        /// Only show rules synced to this tool
+        #[arg(long, value_name = "TOOL")]
+        tool: Option<String>,
+
+        /// Only show rules with this tag
+        #[arg(long, value_name = "TAG")]
+        tag: Option<String>,
+
+        /// Sort order
+        #[arg(long, value_enum, default_value_t)]
+        sort: crate::list::SortKey,
+
+        /// Print the rules as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Delete a rule and every generated tool file
+    #[command(about = "Delete a rule from .agentsync/rules/ and its generated files in every tool")]
+    Remove {
+        /// Name of the rule to delete
+        #[arg(value_name = "RULE_NAME")]
+        name: String,
+
+        /// Delete without asking for confirmation
+        #[arg(long, short = 'y')]
+        yes: bool,
+
+        /// Show what would be deleted without deleting anything
+        #[arg(long, short = 'n')]
+        dry_run: bool,
+    },
+
+    /// Rename a rule in .agentsync/rules/ and every tool
+    #[command(about = "Rename a rule and every generated tool file")]
+    Rename {
+        /// Current rule name
+        #[arg(value_name = "OLD_NAME")]
+        old: String,
+
+        /// New rule name (kebab-case)
+        #[arg(value_name = "NEW_NAME")]
+        new: String,
+
+        /// Show what would be moved without moving anything
+        #[arg(long, short = 'n')]
+        dry_run: bool,
+    },
+
+    /// Print what each tool receives for a rule
+    #[command(
+        about = "Print a rule converted for each enabled tool (or one tool with --as) without writing files"
+    )]
+    Show {
+        /// Name of the rule in .agentsync/rules/
+        #[arg(value_name = "RULE_NAME")]
+        name: String,
+
+        /// Only show the output for this tool
+        #[arg(long = "as", value_name = "TOOL")]
+        tool: Option<String>,
+
+        /// List fields the conversion dropped or filled with defaults
+        #[arg(long)]
+        annotate: bool,
+    },
+
+    /// Show which rules each tool applies to a file, and why
+    #[command(
+        about = "Show which rules each enabled tool applies to a file: always on, matched by a glob, available via description, or manual"
+    )]
+    Explain {
+        /// File path, relative to the current directory (it does not have to exist)
+        #[arg(value_name = "PATH")]
+        path: std::path::PathBuf,
+
+        /// Only explain this tool
+        #[arg(long, value_name = "TOOL")]
+        tool: Option<String>,
+
+        /// Print the explanation as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check that rules survive export to each tool and re-import
+    #[command(
+        about = "Convert every rule to each enabled tool and back, and report fields that change"
+    )]
+    VerifyRoundtrip {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check the paths rules declare in `expect` against each tool's globs
+    #[command(
+        about = "Check each rule's `expect` paths (matches/excludes) against the globs every enabled tool receives"
+    )]
+    Test {
+        /// Only test this rule
+        #[arg(value_name = "RULE")]
+        rule: Option<String>,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Convert a rule between formats on stdin/stdout
+    #[command(
+        about = "Convert a rule read from stdin between formats and print it to stdout (no project needed)"
+    )]
+    Convert {
+        /// Format of the input: cursor, copilot, windsurf, or agentsync
+        #[arg(long, value_name = "TOOL")]
+        from: String,
+
+        /// Format of the output: cursor, copilot, windsurf, or agentsync
+        #[arg(long, value_name = "TOOL")]
+        to: String,
     },
 }
 
@@ -1,22 +1,106 @@
-//! CLI command implementations (`init`, `add`).
+//! CLI command implementations (`init`, `sync`, `add`, `validate`, `status`, `list`, `remove`,
+//! `rename`, `show`, `convert`, `templates`).
 
-use crate::{AgentSyncError, Result, config, fs, sync};
+use crate::converter::{Activation, ConfigMode, create_all_configs, normalize_globs};
+use crate::models::{AgentSyncConfig, AgentSyncRule, Rule};
+use crate::{
+    AgentSyncError, Result, config, detect, expect, explain, fs, list, manage, roundtrip, show,
+    status, sync, templates, validate,
+};
 use itertools::Itertools;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
-/// Initialize AgentSync: create directories, config, and optionally import existing rules
-pub fn run_init(verbose: bool) -> Result<()> {
+/// Where `init` imports existing rules from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    /// Leave existing tool rules alone
+    Skip,
+    Tool(fs::Tool),
+    /// Merge rules from every tool that has some; the first tool wins on name clashes
+    All,
+}
+
+impl std::str::FromStr for ImportSource {
+    type Err = AgentSyncError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "all" => Ok(Self::All),
+            "skip" | "" => Ok(Self::Skip),
+            tool => match tool.parse()? {
+                fs::Tool::AgentSync => Err(AgentSyncError::InvalidTool {
+                    tool: tool.to_string(),
+                }),
+                tool => Ok(Self::Tool(tool)),
+            },
+        }
+    }
+}
+
+/// Options for `agentsync init`
+#[derive(Debug, Clone, Default)]
+pub struct InitOptions {
+    /// Import source chosen up front; `None` asks when running interactively
+    pub import: Option<ImportSource>,
+    /// Tools to enable in `agentsync.json`; empty means the defaults
+    pub tools: Vec<String>,
+    /// Detected starter rules to create without asking; `all` selects every proposal
+    pub scaffold: Vec<String>,
+    /// Never prompt; use the default answers (no import, no starter rules)
+    pub yes: bool,
+}
+
+/// Initialize AgentSync: create directories, config, and optionally import existing rules.
+///
+/// With an explicit `config` path, the project is created in the directory containing it.
+/// Prompts only when stdin is a terminal and neither `--import`, `--no-import` nor
+/// `--yes` was given, so scripts never hang.
+pub fn run_init(
+    config: Option<&Path>,
+    options: &InitOptions,
+    verbose: bool,
+    strict: bool,
+) -> Result<()> {
     use fs_err as fs;
-    use std::io::{self, Write};
+    use std::io::IsTerminal;
 
-    let current_dir = std::env::current_dir()?;
+    let config_path = match config {
+        Some(path) => crate::fs::resolve_config_path(path)?,
+        None => std::env::current_dir()?.join(crate::fs::CONFIG_FILE),
+    };
+    let current_dir = config_path
+        .parent()
+        .ok_or_else(|| {
+            AgentSyncError::Other("Config path must have a parent directory".to_string())
+        })?
+        .to_path_buf();
 
     // Check if already initialized
-    let config_path = current_dir.join("agentsync.json");
     if config_path.exists() {
-        return Err(AgentSyncError::Other(
-            "Project already initialized (agentsync.json exists)".to_string(),
-        ));
+        return Err(AgentSyncError::Other(format!(
+            "Project already initialized ({} exists)",
+            config_path.display()
+        )));
+    }
+
+    let mut project_config = config::create_default_config();
+    if !options.tools.is_empty() {
+        project_config.tools.clone_from(&options.tools);
+    }
+    project_config.validate()?;
+
+    let proposals = detect::detect_proposals(&current_dir);
+    let scaffold_all = options.scaffold.iter().any(|name| name == "all");
+    if let Some(unknown) = options
+        .scaffold
+        .iter()
+        .find(|name| !scaffold_all && !proposals.iter().any(|p| p.name == name.as_str()))
+    {
+        return Err(AgentSyncError::Other(format!(
+            "No starter rule '{unknown}' was detected for this project (detected: {})",
+            proposals.iter().map(|p| p.name).format(", ")
+        )));
     }
 
     let agentsync_dir = current_dir.join(".agentsync/rules");
@@ -26,84 +110,293 @@ pub fn run_init(verbose: bool) -> Result<()> {
     fs::create_dir_all(&agentsync_dir)?;
     println!("✓ Created .agentsync/rules/");
 
-    let default_config = config::create_default_config();
-    config::save_config(&config_path, &default_config)?;
-    println!("✓ Created agentsync.json");
+    config::save_config(&config_path, &project_config)?;
+    println!(
+        "✓ Created {} (tools: {})",
+        config_path.display(),
+        project_config.tools.join(", ")
+    );
 
     // Scan for existing rules in tool directories
     let mut found_tools = Vec::new();
-    for tool_name in &["cursor", "copilot", "windsurf"] {
-        if let Ok(tool) = tool_name.parse::<crate::fs::Tool>() {
-            let rules = crate::fs::discover_rules(&current_dir, tool)?;
-            if !rules.is_empty() {
-                found_tools.push(((*tool_name).to_string(), rules.len()));
-                if verbose {
-                    info!("Found {} rule(s) in {}", rules.len(), tool.directory());
-                }
+    for tool in crate::fs::Tool::SYNC_TARGETS {
+        let rules = crate::fs::discover_rules(&current_dir, tool)?;
+        if !rules.is_empty() {
+            found_tools.push((tool, rules.len()));
+            if verbose {
+                info!("Found {} rule(s) in {}", rules.len(), tool.directory());
             }
         }
     }
 
-    // If rules found, prompt user which to import
     if !found_tools.is_empty() {
         println!("\nFound existing rules:");
         for (tool, count) in &found_tools {
             println!("  - {tool}: {count} rule(s)");
         }
+    }
+
+    let source = match options.import {
+        Some(source) => source,
+        None if found_tools.is_empty() => ImportSource::Skip,
+        None if options.yes || !std::io::stdin().is_terminal() => {
+            println!("\nNot importing (non-interactive). Use --import <tool|all> to import.");
+            ImportSource::Skip
+        }
+        None => prompt_import_source(&found_tools)?,
+    };
+
+    import_existing_rules(&current_dir, source, &found_tools, verbose, strict)?;
+
+    // Starter rules are offered for fresh projects, or whenever --scaffold asks for them
+    if found_tools.is_empty() || !options.scaffold.is_empty() {
+        scaffold_starter_rules(&current_dir, proposals, options)?;
+    }
+
+    println!("\n✓ Initialization complete!");
+    println!("  - Edit rules in .agentsync/rules/");
+    println!("  - Run 'agentsync sync' to propagate changes to tools");
+
+    Ok(())
+}
 
-        print!("\nWhich tool to import from? [");
-        for (i, (tool, _)) in found_tools.iter().enumerate() {
-            if i > 0 {
-                print!("/");
+/// Import rules into a freshly initialized project according to `source`
+fn import_existing_rules(
+    current_dir: &Path,
+    source: ImportSource,
+    found_tools: &[(crate::fs::Tool, usize)],
+    verbose: bool,
+    strict: bool,
+) -> Result<()> {
+    let sync_options = sync::SyncOptions {
+        dry_run: false,
+        verbose,
+        strict,
+        keep_existing: false,
+        ..Default::default()
+    };
+    let result = match source {
+        ImportSource::Skip => {
+            if !found_tools.is_empty() {
+                println!(
+                    "Skipped import. You can import later with 'agentsync sync --from <tool>'"
+                );
             }
-            print!("{tool}");
+            None
         }
-        print!("/skip]: ");
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let choice = input.trim().to_lowercase();
-
-        if choice != "skip" && !choice.is_empty() {
-            let valid_choice = found_tools.iter().any(|(tool, _)| tool == &choice);
-            if !valid_choice {
-                return Err(AgentSyncError::Other(format!(
-                    "Invalid choice '{}'. Expected one of: {}",
-                    choice,
-                    found_tools.iter().map(|(t, _)| t.as_str()).format(", ")
-                )));
+        ImportSource::Tool(tool) => {
+            let result = sync::sync_from_tool(current_dir, tool, &sync_options)?;
+            println!("✓ Imported {} rule(s) from {tool}", result.added.len());
+            Some(result)
+        }
+        ImportSource::All => {
+            let tools: Vec<crate::fs::Tool> = found_tools.iter().map(|(tool, _)| *tool).collect();
+            let result = sync::import_from_tools(current_dir, &tools, &sync_options)?;
+            println!(
+                "✓ Imported {} rule(s) from {}",
+                result.added.len(),
+                tools.iter().format(", ")
+            );
+            Some(result)
+        }
+    };
+
+    if let Some(result) = result {
+        for (rule, warning) in &result.warnings {
+            println!("  ? {rule}: {warning}");
+        }
+        for (rule, error) in &result.errors {
+            println!("  ! {rule}: {error}");
+        }
+
+        if verbose && !result.added.is_empty() {
+            for rule in &result.added {
+                info!("  - {rule}");
             }
+        }
+    }
 
-            // Import from selected tool
-            let tool: crate::fs::Tool = choice.parse()?;
-            let options = sync::SyncOptions {
-                dry_run: false,
-                verbose,
-            };
+    Ok(())
+}
 
-            let result = sync::sync_from_tool(&current_dir, tool, &options)?;
-            println!("✓ Imported {} rule(s) from {}", result.added.len(), choice);
+/// Create the starter rules chosen from `proposals`.
+///
+/// Rules named with `--scaffold` are created directly. Otherwise each proposal is
+/// offered in turn, or only listed when `init` cannot prompt.
+fn scaffold_starter_rules(
+    current_dir: &Path,
+    proposals: Vec<detect::Proposal>,
+    options: &InitOptions,
+) -> Result<()> {
+    use std::io::IsTerminal;
 
-            if verbose && !result.added.is_empty() {
-                for rule in &result.added {
-                    info!("  - {rule}");
-                }
-            }
+    let proposals: Vec<detect::Proposal> = proposals
+        .into_iter()
+        .filter(|proposal| {
+            !current_dir
+                .join(".agentsync/rules")
+                .join(format!("{}.md", proposal.name))
+                .exists()
+        })
+        .collect();
+    if proposals.is_empty() {
+        return Ok(());
+    }
+
+    println!("\nDetected starter rules:");
+    for proposal in &proposals {
+        println!("  - {} (found {})", proposal.name, proposal.reason);
+    }
+
+    let scaffold_all = options.scaffold.iter().any(|name| name == "all");
+    let interactive = !options.yes && std::io::stdin().is_terminal();
+    if options.scaffold.is_empty() && !interactive {
+        println!(
+            "Not creating starter rules (non-interactive). Use --scaffold <rules|all> to create them."
+        );
+        return Ok(());
+    }
+
+    for proposal in &proposals {
+        let chosen = if options.scaffold.is_empty() {
+            confirm(&format!("Create starter rule '{}'?", proposal.name))?
         } else {
-            println!("Skipped import. You can import later with 'agentsync sync --from <tool>'");
+            scaffold_all || options.scaffold.iter().any(|name| name == proposal.name)
+        };
+        if !chosen {
+            continue;
         }
+
+        let template = proposal
+            .template
+            .map(|template| templates::find_template(Some(current_dir), template)?.parse())
+            .transpose()?;
+        let add_options = AddOptions {
+            description: proposal.description.map(str::to_string),
+            globs: proposal.globs.map(str::to_string),
+            ..AddOptions::default()
+        };
+        let rule = build_rule(
+            proposal.name,
+            &add_options,
+            template.as_ref(),
+            proposal.body.map(str::to_string),
+        )?;
+
+        fs::write_rule_file(
+            current_dir
+                .join(".agentsync/rules")
+                .join(format!("{}.md", proposal.name)),
+            &rule,
+        )?;
+        println!("✓ Created .agentsync/rules/{}.md", proposal.name);
     }
 
-    println!("\n✓ Initialization complete!");
-    println!("  - Edit rules in .agentsync/rules/");
-    println!("  - Run 'agentsync sync' to propagate changes to tools");
+    Ok(())
+}
+
+/// Ask which tool to import existing rules from
+fn prompt_import_source(found_tools: &[(crate::fs::Tool, usize)]) -> Result<ImportSource> {
+    use std::io::{self, Write};
+
+    let choices = found_tools.iter().map(|(tool, _)| tool.name()).format("/");
+    print!("\nWhich tool to import from? [{choices}/all/skip]: ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let choice = input.trim().to_lowercase();
+
+    let source: ImportSource = choice
+        .parse()
+        .map_err(|_| invalid_choice(&choice, found_tools))?;
+    if let ImportSource::Tool(tool) = source
+        && !found_tools.iter().any(|(found, _)| *found == tool)
+    {
+        return Err(invalid_choice(&choice, found_tools));
+    }
+
+    Ok(source)
+}
+
+fn invalid_choice(choice: &str, found_tools: &[(crate::fs::Tool, usize)]) -> AgentSyncError {
+    AgentSyncError::Other(format!(
+        "Invalid choice '{}'. Expected one of: {}, all, skip",
+        choice,
+        found_tools.iter().map(|(t, _)| t.name()).format(", ")
+    ))
+}
+
+/// Sync `.agentsync/rules/` to every enabled tool, or import from `from` when given
+pub fn run_sync(
+    from: Option<&str>,
+    config: Option<&Path>,
+    options: &sync::SyncOptions,
+) -> Result<()> {
+    let paths = fs::locate_project(config)?;
+
+    let result = if let Some(tool_name) = from {
+        info!("Running sync --from {tool_name}");
+        let tool: fs::Tool = tool_name.parse()?;
 
+        println!("Syncing from {tool_name} to .agentsync/rules/...");
+        sync::sync_from_tool(&paths.root, tool, options)?
+    } else {
+        info!("Running sync to tools");
+        let config = config::load_config(&paths.config)?;
+        config.validate()?;
+        let options = sync::SyncOptions {
+            fidelity: config.fidelity.clone(),
+            limits: config.limits.clone(),
+            ..options.clone()
+        };
+
+        println!("Syncing from .agentsync/rules/ to enabled tools...");
+        sync::sync_to_tools(&paths.root, &config.tools, &options)?
+    };
+
+    result.print_summary(options.dry_run);
+
+    let failures = result.fidelity_failures();
+    if failures > 0 {
+        return Err(AgentSyncError::Other(format!(
+            "{failures} lossy conversion(s) refused by a `fail` fidelity policy in {}",
+            fs::CONFIG_FILE
+        )));
+    }
+    let failures = result.budget.failures();
+    if failures > 0 {
+        return Err(AgentSyncError::Other(format!(
+            "{failures} size limit(s) exceeded under `onExceed: fail` in {}",
+            fs::CONFIG_FILE
+        )));
+    }
     Ok(())
 }
 
-/// Create a new rule template in `.agentsync/rules/`
-pub fn run_add(name: &str, verbose: bool) -> Result<()> {
+/// Options for `agentsync add`; anything left unset falls back to the default template
+#[derive(Debug, Clone, Default)]
+pub struct AddOptions {
+    pub description: Option<String>,
+    pub globs: Option<String>,
+    /// Tool names; empty means all tools
+    pub targets: Vec<String>,
+    pub mode: Option<Activation>,
+    /// File to read the body from, `-` for stdin
+    pub from_file: Option<PathBuf>,
+    /// Open the created rule in `$VISUAL`/`$EDITOR`
+    pub edit: bool,
+    /// Start from this template; the other options override its values
+    pub template: Option<String>,
+}
+
+/// Create a new rule in `.agentsync/rules/`
+pub fn run_add(
+    name: &str,
+    config: Option<&Path>,
+    options: &AddOptions,
+    verbose: bool,
+) -> Result<()> {
     if name.is_empty() {
         return Err(AgentSyncError::Other(
             "Rule name cannot be empty".to_string(),
@@ -126,7 +419,7 @@ pub fn run_add(name: &str, verbose: bool) -> Result<()> {
         ));
     }
 
-    let project_root = fs::find_project_root()?;
+    let project_root = fs::locate_project(config)?.root;
     let rule_path = project_root
         .join(".agentsync/rules")
         .join(format!("{name}.md"));
@@ -141,26 +434,542 @@ pub fn run_add(name: &str, verbose: bool) -> Result<()> {
         )));
     }
 
-    let template = create_rule_template(name);
+    let template = options
+        .template
+        .as_deref()
+        .map(|template| templates::find_template(Some(&project_root), template)?.parse())
+        .transpose()?;
+    let body = options.from_file.as_deref().map(read_body).transpose()?;
+    let template = build_rule(name, options, template.as_ref(), body)?;
 
     if verbose {
         info!("Creating rule file: {}", rule_path.display());
     }
 
     fs::write_rule_file(&rule_path, &template)?;
-
     println!("✓ Created .agentsync/rules/{name}.md");
-    println!("Edit the rule, then run 'agentsync sync' to propagate to tools.");
+
+    if options.edit {
+        open_in_editor(&rule_path)?;
+        report_rule_problems(&rule_path)?;
+    } else if options.from_file.is_none() {
+        println!("Edit the rule, then run 'agentsync sync' to propagate to tools.");
+    } else {
+        println!("Run 'agentsync sync' to propagate to tools.");
+    }
+
+    Ok(())
+}
+
+/// List templates available to `agentsync add --template`.
+///
+/// Outside a project only the built-in templates are shown.
+pub fn run_templates(config: Option<&Path>, json: bool) -> Result<()> {
+    use owo_colors::OwoColorize;
+
+    let project_root = match config {
+        Some(_) => Some(fs::locate_project(config)?.root),
+        None => fs::locate_project(None).ok().map(|paths| paths.root),
+    };
+    let summaries = templates::list_templates(project_root.as_deref())?
+        .iter()
+        .map(templates::Template::summary)
+        .collect::<Result<Vec<_>>>()?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summaries)?);
+        return Ok(());
+    }
+
+    let width = summaries.iter().map(|t| t.name.len()).max().unwrap_or(0);
+    for summary in &summaries {
+        let mode = if summary.globs.is_empty() {
+            summary.activation.to_string()
+        } else {
+            format!("{} {}", summary.activation, summary.globs)
+        };
+        println!(
+            "  {:width$}  {:8}  {mode}\n  {:width$}  {}",
+            summary.name,
+            summary.source,
+            "",
+            summary.description.dimmed(),
+        );
+    }
+    println!(
+        "\nUse one with 'agentsync add <name> --template <template>'. Add your own in {}/.",
+        templates::TEMPLATES_DIR
+    );
+
+    Ok(())
+}
+
+/// Read a rule body from a file, or from stdin for `-`
+fn read_body(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        Ok(std::io::read_to_string(std::io::stdin())?)
+    } else {
+        Ok(fs_err::read_to_string(path)?)
+    }
+}
+
+/// Build the rule file for `agentsync add` from its flags.
+///
+/// The mode is turned into consistent per-tool settings with `create_all_configs`,
+/// and only the targeted tools get a section. Flags that are not given fall back
+/// to `template`, when there is one.
+fn build_rule(
+    name: &str,
+    options: &AddOptions,
+    template: Option<&Rule<AgentSyncRule>>,
+    body: Option<String>,
+) -> Result<String> {
+    let template_mode =
+        template.map(|template| templates::activation_and_globs(&template.frontmatter));
+    // The template's activation only applies when neither --mode nor --globs is given
+    let mode = match (options.mode, &options.globs, &template_mode) {
+        (Some(mode), _, _) => mode,
+        (None, Some(_), _) => Activation::Glob,
+        (None, None, Some((activation, _))) => *activation,
+        (None, None, None) => Activation::ModelDecision,
+    };
+
+    // --globs wins; otherwise a glob-based mode falls back to the template's globs
+    let globs = match (&options.globs, template_mode) {
+        (Some(globs), _) => normalize_globs(globs),
+        (None, Some((_, globs))) if mode.uses_globs() => globs,
+        (None, _) => String::new(),
+    };
+    if mode.uses_globs() && globs.is_empty() {
+        return Err(AgentSyncError::Other(format!(
+            "--mode {mode} requires --globs (e.g. --globs \"src/**/*.rs\")"
+        )));
+    }
+    if !mode.uses_globs() && !globs.is_empty() {
+        return Err(AgentSyncError::Other(format!(
+            "--globs only applies to glob-based modes, but --mode is {mode}"
+        )));
+    }
+
+    for target in &options.targets {
+        if target != "*" {
+            target.parse::<fs::Tool>()?;
+        }
+    }
+    let targets = match template {
+        _ if !options.targets.is_empty() => options.targets.clone(),
+        Some(template) if !template.frontmatter.targets.is_empty() => {
+            template.frontmatter.targets.clone()
+        }
+        _ => vec!["*".to_string()],
+    };
+    let targets_tool = |tool: &str| targets.iter().any(|t| t == "*" || t == tool);
+
+    let (cursor, windsurf, copilot, globs) = create_all_configs(
+        &ConfigMode::from_activation(mode, &globs),
+        fs::Tool::AgentSync,
+    );
+
+    let frontmatter = AgentSyncRule {
+        description: options
+            .description
+            .clone()
+            .or_else(|| template.map(|template| template.frontmatter.description.clone()))
+            .unwrap_or_else(|| "Description of this rule".to_string()),
+        tags: template
+            .map(|template| template.frontmatter.tags.clone())
+            .unwrap_or_default(),
+        activation: Some(mode),
+        globs,
+        exclude_globs: String::new(),
+        cursor: targets_tool("cursor").then_some(cursor),
+        windsurf: targets_tool("windsurf").then_some(windsurf),
+        copilot: targets_tool("copilot").then_some(copilot),
+        targets,
+        expect: None,
+    };
+
+    let mut content = body
+        .or_else(|| template.map(|template| template.content.trim_start().to_string()))
+        .unwrap_or_else(|| format!("# {}\n\nYour rule content here...\n", rule_title(name)));
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+
+    crate::parser::serialize_frontmatter(&Rule {
+        frontmatter,
+        content,
+    })
+}
+
+/// Open `path` in the user's editor and wait for it to exit
+fn open_in_editor(path: &Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .map_err(|_| {
+            AgentSyncError::Other("--edit requires $VISUAL or $EDITOR to be set".to_string())
+        })?;
+
+    // Allow editors with arguments, e.g. `code --wait`
+    let mut parts = editor.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| AgentSyncError::Other("$EDITOR is empty".to_string()))?;
+
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(AgentSyncError::Other(format!(
+            "Editor `{editor}` exited with {status}; the rule was kept at {}",
+            path.display()
+        )))
+    }
+}
+
+/// Print frontmatter problems in a rule the user just edited
+fn report_rule_problems(path: &Path) -> Result<()> {
+    let content = fs::read_rule_file(path)?;
+    let path_str = path.display().to_string();
+
+    match crate::parser::check_frontmatter::<AgentSyncRule>(&content, Some(&path_str)) {
+        Ok(issues) => {
+            for issue in &issues {
+                println!("  ? {issue}");
+            }
+        }
+        Err(e) => println!("{e}"),
+    }
+
+    Ok(())
+}
+
+/// Validate configuration and rules, printing every problem found
+pub fn run_validate(
+    tool: Option<&str>,
+    config: Option<&Path>,
+    globs: bool,
+    strict: bool,
+) -> Result<()> {
+    let paths = fs::locate_project(config)?;
+    let project_root = paths.root;
+
+    let report = if let Some(tool_name) = tool {
+        let tool: fs::Tool = tool_name.parse()?;
+        println!("Validating {} rules in {}/...", tool, tool.directory());
+        validate::validate_tool_rules(&project_root, tool)?
+    } else {
+        println!(
+            "Validating {} and .agentsync/rules/...",
+            paths.config.display()
+        );
+        let mut report = validate::validate_project(&project_root, &paths.config)?;
+        if globs {
+            let tools = config::load_config(&paths.config)
+                .map_or_else(|_| AgentSyncConfig::default().tools, |config| config.tools);
+            report.extend(validate::validate_glob_coverage(&project_root, &tools)?);
+        }
+        report
+    };
+
+    report.print();
+
+    if report.is_valid(strict) {
+        Ok(())
+    } else {
+        Err(AgentSyncError::ValidationFailed {
+            errors: report.error_count(),
+            warnings: report.warning_count(),
+        })
+    }
+}
+
+/// Load and validate the project config, returning the project root and enabled tools
+fn load_enabled_tools(config: Option<&Path>) -> Result<(std::path::PathBuf, Vec<fs::Tool>)> {
+    let (root, config) = load_project_config(config)?;
+    Ok((root, enabled_tools(&config)?))
+}
+
+/// Load and validate the project config, returning the project root and the config
+fn load_project_config(config: Option<&Path>) -> Result<(std::path::PathBuf, AgentSyncConfig)> {
+    let paths = fs::locate_project(config)?;
+    let config = config::load_config(&paths.config)?;
+    config.validate()?;
+
+    Ok((paths.root, config))
+}
+
+fn enabled_tools(config: &AgentSyncConfig) -> Result<Vec<fs::Tool>> {
+    config.tools.iter().map(|name| name.parse()).collect()
+}
+
+/// Show the sync state of every rule in every enabled tool
+pub fn run_status(config: Option<&Path>, json: bool) -> Result<()> {
+    let (project_root, config) = load_project_config(config)?;
+    let tools = enabled_tools(&config)?;
+    let report = status::project_status(&project_root, &tools, &config.limits, &config.fidelity)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        report.print();
+    }
+
+    Ok(())
+}
+
+/// List rules with their targets, per-tool activation, and globs
+pub fn run_list(
+    config: Option<&Path>,
+    filter: &list::ListFilter,
+    sort: list::SortKey,
+    json: bool,
+) -> Result<()> {
+    let (project_root, tools) = load_enabled_tools(config)?;
+    let listing = list::list_rules(&project_root, &tools, filter, sort)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&listing.rules)?);
+    } else {
+        list::print_listing(&listing);
+    }
+
+    Ok(())
+}
+
+/// Delete a rule's source file and every generated tool file
+pub fn run_remove(name: &str, config: Option<&Path>, yes: bool, dry_run: bool) -> Result<()> {
+    let project_root = fs::locate_project(config)?.root;
+    let files = manage::existing_rule_files(&project_root, name)?;
+
+    let prefix = if dry_run { "[DRY RUN] " } else { "" };
+    println!("{prefix}Rule '{name}' has {} file(s):", files.len());
+    for file in &files {
+        println!("  - {}", fs::display_relative(&project_root, &file.path));
+    }
+
+    if dry_run {
+        println!("\nNo files were deleted (dry-run mode)");
+        return Ok(());
+    }
+
+    if !yes && !confirm(&format!("Delete {} file(s)?", files.len()))? {
+        println!("Aborted. Nothing was deleted.");
+        return Ok(());
+    }
+
+    manage::remove_rule_files(&files)?;
+    println!("✓ Removed rule '{name}' ({} file(s))", files.len());
+
+    Ok(())
+}
+
+/// Rename a rule's source file and every generated tool file
+pub fn run_rename(old: &str, new: &str, config: Option<&Path>, dry_run: bool) -> Result<()> {
+    let project_root = fs::locate_project(config)?.root;
+    let moves = manage::plan_rename(&project_root, old, new)?;
+
+    let prefix = if dry_run { "[DRY RUN] " } else { "" };
+    println!("{prefix}Renaming '{old}' to '{new}':");
+    for step in &moves {
+        println!(
+            "  {} → {}",
+            fs::display_relative(&project_root, &step.from),
+            fs::display_relative(&project_root, &step.to)
+        );
+    }
+
+    if dry_run {
+        println!("\nNo files were moved (dry-run mode)");
+        return Ok(());
+    }
+
+    manage::apply_moves(&moves)?;
+    println!(
+        "✓ Renamed rule '{old}' to '{new}' ({} file(s))",
+        moves.len()
+    );
+
+    Ok(())
+}
+
+/// Print a rule converted for each enabled tool, or only `tool`, without writing files.
+///
+/// With a single tool and no annotations the output is exactly the file contents,
+/// so it can be redirected.
+pub fn run_show(
+    name: &str,
+    tool: Option<fs::Tool>,
+    config: Option<&Path>,
+    annotate: bool,
+) -> Result<()> {
+    use owo_colors::OwoColorize;
+
+    let (project_root, enabled_tools) = load_enabled_tools(config)?;
+    let tools = tool.map_or(enabled_tools, |tool| vec![tool]);
+    let previews = show::preview_rule(&project_root, name, &tools)?;
+    let raw = tool.is_some() && !annotate;
+
+    for preview in &previews {
+        if tool.is_none() && !preview.targeted {
+            println!(
+                "{}",
+                format!("── {} (not targeted, skipped by sync) ──\n", preview.tool).dimmed()
+            );
+            continue;
+        }
+
+        if !raw {
+            println!(
+                "{}",
+                format!(
+                    "── {} ({}) ──",
+                    preview.tool,
+                    fs::display_relative(&project_root, &preview.path)
+                )
+                .bold()
+            );
+            if !preview.targeted {
+                println!(
+                    "{}",
+                    format!(
+                        "(rule does not target {}; sync would not write this file)",
+                        preview.tool
+                    )
+                    .yellow()
+                );
+            }
+        }
+
+        print!("{}", preview.content);
+
+        if annotate {
+            if preview.notes.is_empty() {
+                println!("{}", "note: nothing dropped or defaulted".dimmed());
+            }
+            for note in &preview.notes {
+                println!("{} {note}", "note:".cyan().bold());
+            }
+        }
+        if !raw {
+            println!();
+        }
+    }
+
+    Ok(())
+}
+
+/// Explain which rules each enabled tool (or just `tool`) applies to `path`
+pub fn run_explain(
+    path: &Path,
+    tool: Option<fs::Tool>,
+    config: Option<&Path>,
+    json: bool,
+) -> Result<()> {
+    let (project_root, enabled_tools) = load_enabled_tools(config)?;
+    let tools = tool.map_or(enabled_tools, |tool| vec![tool]);
+    let path = explain::project_relative_path(&project_root, &std::env::current_dir()?, path)?;
+    let explanation = explain::explain_path(&project_root, &path, &tools)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&explanation)?);
+    } else {
+        explanation.print();
+    }
 
     Ok(())
 }
 
-/// Generate rule template with YAML frontmatter. Converts kebab-case to Title Case.
-fn create_rule_template(name: &str) -> String {
-    use indoc::formatdoc;
+/// Round-trip every rule through each enabled tool and fail if any changes
+pub fn run_verify_roundtrip(config: Option<&Path>, json: bool) -> Result<()> {
+    let (project_root, tools) = load_enabled_tools(config)?;
+    let report = roundtrip::verify_roundtrip(&project_root, &tools)?;
 
-    let title = name
-        .replace('-', " ")
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        report.print();
+    }
+
+    if let Some((rule, error)) = report.errors.first() {
+        return Err(AgentSyncError::Other(format!(
+            "Could not read {} rule(s), first: {rule}: {error}",
+            report.errors.len()
+        )));
+    }
+    let changed = report.changed_count();
+    if changed > 0 {
+        return Err(AgentSyncError::RoundtripUnstable { changed });
+    }
+    Ok(())
+}
+
+/// Check every rule's `expect` paths in each enabled tool and fail if any don't hold
+pub fn run_test(rule: Option<&str>, config: Option<&Path>, json: bool) -> Result<()> {
+    let (project_root, tools) = load_enabled_tools(config)?;
+    let report = expect::test_expectations(&project_root, &tools, rule)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        report.print();
+    }
+
+    if let Some((rule, error)) = report.errors.first() {
+        return Err(AgentSyncError::Other(format!(
+            "Could not read {} rule(s), first: {rule}: {error}",
+            report.errors.len()
+        )));
+    }
+    let failed = report.count(expect::Outcome::Fail);
+    if failed > 0 {
+        return Err(AgentSyncError::ExpectationsFailed { failed });
+    }
+    Ok(())
+}
+
+/// Convert a rule from stdin to stdout. Works outside any project.
+pub fn run_convert(from: fs::Tool, to: fs::Tool) -> Result<()> {
+    use std::io::{self, Write};
+
+    let input = io::read_to_string(io::stdin())?;
+    let output = crate::processor::convert(&input, "<stdin>", from, to)?;
+
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(output.as_bytes())?;
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Ask a yes/no question on stdin, defaulting to no.
+///
+/// Fails instead of prompting when stdin is not a terminal, so scripts must pass `--yes`.
+fn confirm(question: &str) -> Result<bool> {
+    use std::io::{self, IsTerminal, Write};
+
+    if !io::stdin().is_terminal() {
+        return Err(AgentSyncError::Other(
+            "Refusing to continue without confirmation: stdin is not a terminal (pass --yes to skip the prompt)"
+                .to_string(),
+        ));
+    }
+
+    print!("\n{question} [y/N]: ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Convert kebab-case to Title Case for the rule heading
+fn rule_title(name: &str) -> String {
+    name.replace('-', " ")
         .split_whitespace()
         .map(|word| {
             let mut chars = word.chars();
@@ -170,34 +979,18 @@ fn create_rule_template(name: &str) -> String {
             }
         })
         .format(" ")
-        .to_string();
-
-    formatdoc! {"
-        ---
-        targets:
-          - \"*\"
-        description: \"Description of this rule\"
-        globs: \"**/*\"
-        cursor:
-          alwaysApply: false
-          globs: \"\"
-        windsurf:
-          trigger: model_decision
-          globs: \"\"
-        copilot:
-          applyTo: \"**\"
-        ---
-        # {title}
-
-        Your rule content here...
-        "
-    }
+        .to_string()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// The template `agentsync add` writes without any flags
+    fn create_rule_template(name: &str) -> String {
+        build_rule(name, &AddOptions::default(), None, None).expect("default template should build")
+    }
+
     #[test]
     fn test_create_rule_template() {
         let template = create_rule_template("python-dev");
@@ -223,7 +1016,7 @@ mod tests {
     #[test]
     fn test_run_add_rejects_path_traversal() {
         // Test that path traversal attempts are rejected
-        let result = run_add("../../../etc/passwd", false);
+        let result = run_add("../../../etc/passwd", None, &AddOptions::default(), false);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
@@ -233,7 +1026,7 @@ mod tests {
 
     #[test]
     fn test_run_add_rejects_forward_slash() {
-        let result = run_add("subdir/rule", false);
+        let result = run_add("subdir/rule", None, &AddOptions::default(), false);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
@@ -243,7 +1036,7 @@ mod tests {
 
     #[test]
     fn test_run_add_rejects_backslash() {
-        let result = run_add("subdir\\rule", false);
+        let result = run_add("subdir\\rule", None, &AddOptions::default(), false);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
@@ -253,11 +1046,106 @@ mod tests {
 
     #[test]
     fn test_run_add_rejects_dot_dot() {
-        let result = run_add("..rule", false);
+        let result = run_add("..rule", None, &AddOptions::default(), false);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
             AgentSyncError::PathTraversal { .. }
         ));
     }
+
+    #[test]
+    fn test_build_rule_glob_mode_with_targets() {
+        let options = AddOptions {
+            description: Some("Python style".to_string()),
+            globs: Some("src/**/*.py, tests/**/*.py".to_string()),
+            targets: vec!["cursor".to_string(), "windsurf".to_string()],
+            ..AddOptions::default()
+        };
+
+        let rule = build_rule("python", &options, None, Some("Use black.".to_string()))
+            .expect("should build rule");
+
+        assert!(rule.contains("targets: cursor,windsurf"));
+        assert!(rule.contains("description: Python style"));
+        assert!(rule.contains("globs: src/**/*.py,tests/**/*.py"));
+        assert!(rule.contains("trigger: glob"));
+        assert!(!rule.contains("copilot:"));
+        assert!(rule.ends_with("Use black.\n"));
+
+        let parsed: Rule<AgentSyncRule> =
+            crate::parser::parse_frontmatter(&rule, None).expect("should parse back");
+        assert_eq!(
+            parsed.frontmatter.cursor.expect("cursor section").globs,
+            "src/**/*.py,tests/**/*.py"
+        );
+    }
+
+    #[test]
+    fn test_build_rule_always_mode() {
+        let options = AddOptions {
+            mode: Some(Activation::Always),
+            ..AddOptions::default()
+        };
+
+        let rule = build_rule("core", &options, None, None).expect("should build rule");
+
+        assert!(rule.contains("alwaysApply: true"));
+        assert!(rule.contains("trigger: always_on"));
+        assert!(rule.contains("applyTo: **"));
+    }
+
+    #[test]
+    fn test_build_rule_rejects_inconsistent_flags() {
+        let glob_without_globs = AddOptions {
+            mode: Some(Activation::Glob),
+            ..AddOptions::default()
+        };
+        let err = build_rule("x", &glob_without_globs, None, None).expect_err("needs globs");
+        assert!(err.to_string().contains("--mode glob requires --globs"));
+
+        let globs_with_manual = AddOptions {
+            mode: Some(Activation::Manual),
+            globs: Some("**/*.rs".to_string()),
+            ..AddOptions::default()
+        };
+        assert!(build_rule("x", &globs_with_manual, None, None).is_err());
+
+        let bad_target = AddOptions {
+            targets: vec!["cursr".to_string()],
+            ..AddOptions::default()
+        };
+        assert!(matches!(
+            build_rule("x", &bad_target, None, None),
+            Err(AgentSyncError::InvalidTool { .. })
+        ));
+    }
+
+    #[test]
+    fn test_build_rule_from_template() {
+        let template = templates::find_template(None, "typescript")
+            .and_then(|template| template.parse())
+            .expect("built-in template should parse");
+
+        let rule = build_rule("ts", &AddOptions::default(), Some(&template), None)
+            .expect("should build rule");
+        assert!(rule.contains("description: TypeScript coding conventions"));
+        assert!(rule.contains("tags: typescript"));
+        assert!(rule.contains("trigger: glob"));
+        assert!(rule.contains("globs: **/*.ts,**/*.tsx"));
+        assert!(rule.contains("# TypeScript"));
+
+        // Flags win over the template
+        let options = AddOptions {
+            mode: Some(Activation::Manual),
+            targets: vec!["windsurf".to_string()],
+            ..AddOptions::default()
+        };
+        let rule = build_rule("ts", &options, Some(&template), Some("Ours.".to_string()))
+            .expect("should build rule");
+        assert!(rule.contains("trigger: manual"));
+        assert!(rule.contains("targets: windsurf"));
+        assert!(!rule.contains("cursor:"));
+        assert!(rule.ends_with("Ours.\n"));
+    }
 }
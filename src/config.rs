@@ -207,6 +207,7 @@ mod tests {
         let config = AgentSyncConfig {
             tools: vec!["cursor".to_string(), "windsurf".to_string()],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
 
         save_config(file.path(), &config).expect("should save config");
@@ -223,6 +224,7 @@ mod tests {
         let config = AgentSyncConfig {
             tools: vec!["invalid".to_string()],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
 
         let result = save_config(file.path(), &config);
@@ -244,18 +246,21 @@ mod tests {
         let valid_config = AgentSyncConfig {
             tools: vec!["cursor".to_string()],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
         assert!(valid_config.validate().is_ok());
 
         let invalid_tool_config = AgentSyncConfig {
             tools: vec!["unknown".to_string()],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
         assert!(invalid_tool_config.validate().is_err());
 
         let empty_dirs_config = AgentSyncConfig {
             tools: vec!["cursor".to_string()],
             base_dirs: vec![],
+            ..Default::default()
         };
         assert!(empty_dirs_config.validate().is_err());
     }
@@ -266,6 +271,7 @@ mod tests {
         let typo_config = AgentSyncConfig {
             tools: vec!["github-copilot".to_string()],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
         let result = typo_config.validate();
         assert!(result.is_err());
@@ -276,6 +282,7 @@ mod tests {
         let unsupported_config = AgentSyncConfig {
             tools: vec!["codeium".to_string()],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
         let result = unsupported_config.validate();
         assert!(result.is_err());
@@ -289,6 +296,7 @@ mod tests {
         let traversal_config = AgentSyncConfig {
             tools: vec!["cursor".to_string()],
             base_dirs: vec![".".to_string(), "../other-project".to_string()],
+            ..Default::default()
         };
         let result = traversal_config.validate();
         assert!(result.is_err());
@@ -301,6 +309,7 @@ mod tests {
         let empty_base_dir_config = AgentSyncConfig {
             tools: vec!["cursor".to_string()],
             base_dirs: vec![".".to_string(), String::new()],
+            ..Default::default()
         };
         let result = empty_base_dir_config.validate();
         assert!(result.is_err());
@@ -315,6 +324,7 @@ mod tests {
                 "windsurf".to_string(),
             ],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
         assert!(all_tools_config.validate().is_ok());
     }
@@ -1,37 +1,91 @@
 //! Copilot-specific conversions
 
 use super::{
-    ConfigMode, GLOB_UNIVERSAL_DOUBLE_STAR, TARGET_ALL, create_all_configs, is_universal_glob,
-    normalize_globs,
+    Activation, ConfigMode, ConversionNote, GLOB_UNIVERSAL_DOUBLE_STAR, TARGET_ALL,
+    activation_globs, apply_exclusions, create_all_configs, included_patterns, is_universal_glob,
+    negated_patterns, normalize_globs, top_level_globs,
 };
+use crate::fs::Tool;
 use crate::models::{AgentSyncRule, CopilotRule, Rule};
 
-/// Convert Copilot rule to `AgentSync` rule
-#[must_use]
-pub fn copilot_to_agentsync(copilot_rule: &CopilotRule) -> AgentSyncRule {
-    let mode = if is_universal_glob(&copilot_rule.apply_to) {
+fn copilot_mode(copilot_rule: &CopilotRule) -> ConfigMode<'_> {
+    if is_universal_glob(&copilot_rule.apply_to) {
         ConfigMode::AlwaysOn
     } else {
         ConfigMode::Glob(&copilot_rule.apply_to)
+    }
+}
+
+/// How Copilot activates this rule (Copilot only supports always-on and glob rules)
+#[must_use]
+pub fn copilot_activation(copilot_rule: &CopilotRule) -> Activation {
+    copilot_mode(copilot_rule).activation()
+}
+
+/// Convert Copilot rule to `AgentSync` rule
+#[must_use]
+pub fn copilot_to_agentsync(copilot_rule: &CopilotRule) -> AgentSyncRule {
+    // Copilot has no `!` patterns; any written in are kept as the exclusions they were meant as
+    let included = CopilotRule {
+        apply_to: included_patterns(&copilot_rule.apply_to),
+        ..copilot_rule.clone()
     };
+    let mode = copilot_mode(&included);
 
-    let (cursor_config, windsurf_config, copilot_config, globs) = create_all_configs(&mode);
+    let (cursor_config, windsurf_config, copilot_config, globs) =
+        create_all_configs(&mode, Tool::Copilot);
 
     AgentSyncRule {
         targets: vec![TARGET_ALL.to_string()],
         description: copilot_rule.description.clone(),
+        tags: Vec::new(),
+        activation: Some(mode.activation()),
         globs,
+        exclude_globs: negated_patterns(&copilot_rule.apply_to, Tool::Copilot),
         cursor: Some(cursor_config),
         windsurf: Some(windsurf_config),
         copilot: Some(copilot_config),
+        expect: None,
     }
 }
 
 /// Convert `AgentSync` rule to Copilot rule
 #[must_use]
 pub fn agentsync_to_copilot(agentsync_rule: &AgentSyncRule) -> CopilotRule {
+    let mut copilot = copilot_rule_for(agentsync_rule);
+    // `**` makes the rule always-on, where exclusions don't apply
+    if !is_universal_glob(&copilot.apply_to) {
+        copilot.apply_to = apply_exclusions(
+            &copilot.apply_to,
+            &agentsync_rule.exclude_globs,
+            Tool::Copilot,
+        )
+        .globs;
+    }
+    copilot
+}
+
+/// Copilot rule for `agentsync_rule`, before `excludeGlobs` are applied
+fn copilot_rule_for(agentsync_rule: &AgentSyncRule) -> CopilotRule {
     let copilot_config = agentsync_rule.copilot.as_ref();
 
+    if let Some(activation) = agentsync_rule.activation {
+        let globs = activation_globs(
+            agentsync_rule,
+            Tool::Copilot,
+            copilot_config.map(|c| c.apply_to.as_str()),
+        );
+        let apply_to = if activation.uses_globs() && !globs.is_empty() {
+            globs
+        } else {
+            GLOB_UNIVERSAL_DOUBLE_STAR.to_string()
+        };
+        return CopilotRule {
+            description: agentsync_rule.description.clone(),
+            apply_to,
+        };
+    }
+
     CopilotRule {
         description: agentsync_rule.description.clone(),
         apply_to: copilot_config.map_or_else(
@@ -40,7 +94,7 @@ pub fn agentsync_to_copilot(agentsync_rule: &AgentSyncRule) -> CopilotRule {
                 if is_universal_glob(&agentsync_rule.globs) {
                     GLOB_UNIVERSAL_DOUBLE_STAR.to_string()
                 } else {
-                    normalize_globs(&agentsync_rule.globs)
+                    top_level_globs(agentsync_rule, Tool::Copilot)
                 }
             },
             |c| normalize_globs(&c.apply_to),
@@ -66,6 +120,23 @@ pub fn agentsync_rule_to_copilot(rule: &Rule<AgentSyncRule>) -> Rule<CopilotRule
     }
 }
 
+/// Fields of an `AgentSync` rule that Copilot drops or fills with defaults
+#[must_use]
+pub fn copilot_notes(agentsync_rule: &AgentSyncRule) -> Vec<ConversionNote> {
+    if agentsync_rule.copilot.is_some() {
+        return Vec::new();
+    }
+
+    let note = if is_universal_glob(&agentsync_rule.globs) {
+        format!(
+            "`copilot.applyTo` defaulted to `{GLOB_UNIVERSAL_DOUBLE_STAR}` (no `copilot` section)"
+        )
+    } else {
+        "`copilot.applyTo` defaulted to the top-level `globs`".to_string()
+    };
+    vec![ConversionNote::defaulted("copilot.applyTo", note)]
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
@@ -123,12 +194,16 @@ mod tests {
         let agentsync = AgentSyncRule {
             targets: vec!["*".to_string()],
             description: "Test rule".to_string(),
+            tags: Vec::new(),
+            activation: None,
             globs: "**/*.rs".to_string(),
+            exclude_globs: String::new(),
             cursor: None,
             windsurf: None,
             copilot: Some(CopilotConfig {
                 apply_to: "**/*.rs".to_string(),
             }),
+            expect: None,
         };
 
         let copilot = agentsync_to_copilot(&agentsync);
@@ -142,10 +217,14 @@ mod tests {
         let agentsync = AgentSyncRule {
             targets: vec!["*".to_string()],
             description: "Test rule".to_string(),
+            tags: Vec::new(),
+            activation: None,
             globs: "**/*.rs".to_string(),
+            exclude_globs: String::new(),
             cursor: None,
             windsurf: None,
             copilot: None,
+            expect: None,
         };
 
         let copilot = agentsync_to_copilot(&agentsync);
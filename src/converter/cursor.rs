@@ -1,37 +1,87 @@
 //! Cursor-specific conversions
 
-use super::{ConfigMode, TARGET_ALL, create_all_configs, is_universal_glob, normalize_globs};
+use super::{
+    Activation, ConfigMode, ConversionNote, TARGET_ALL, activation_globs, apply_exclusions,
+    create_all_configs, is_universal_glob, negated_patterns, normalize_globs, top_level_globs,
+};
+use crate::fs::Tool;
 use crate::models::{AgentSyncRule, CursorRule, Rule};
 
-/// Convert Cursor rule to `AgentSync` rule
-#[must_use]
-pub fn cursor_to_agentsync(cursor_rule: &CursorRule) -> AgentSyncRule {
-    let mode = if cursor_rule.always_apply {
+fn cursor_mode(cursor_rule: &CursorRule) -> ConfigMode<'_> {
+    if cursor_rule.always_apply {
         ConfigMode::AlwaysOn
+    } else if !cursor_rule.globs.is_empty() && !cursor_rule.description.is_empty() {
+        ConfigMode::GlobOrModel(&cursor_rule.globs)
     } else if !cursor_rule.globs.is_empty() {
         ConfigMode::Glob(&cursor_rule.globs)
     } else if !cursor_rule.description.is_empty() {
         ConfigMode::Intelligent
     } else {
         ConfigMode::Manual
-    };
+    }
+}
+
+/// How Cursor activates this rule
+#[must_use]
+pub fn cursor_activation(cursor_rule: &CursorRule) -> Activation {
+    cursor_mode(cursor_rule).activation()
+}
 
-    let (cursor_config, windsurf_config, copilot_config, globs) = create_all_configs(&mode);
+/// Convert Cursor rule to `AgentSync` rule
+#[must_use]
+pub fn cursor_to_agentsync(cursor_rule: &CursorRule) -> AgentSyncRule {
+    let mode = cursor_mode(cursor_rule);
+
+    let (cursor_config, windsurf_config, copilot_config, globs) =
+        create_all_configs(&mode, Tool::Cursor);
 
     AgentSyncRule {
         targets: vec![TARGET_ALL.to_string()],
         description: cursor_rule.description.clone(),
+        tags: Vec::new(),
+        activation: Some(mode.activation()),
         globs,
+        exclude_globs: negated_patterns(&cursor_rule.globs, Tool::Cursor),
         cursor: Some(cursor_config),
         windsurf: Some(windsurf_config),
         copilot: Some(copilot_config),
+        expect: None,
     }
 }
 
 /// Convert `AgentSync` rule to Cursor rule
 #[must_use]
 pub fn agentsync_to_cursor(agentsync_rule: &AgentSyncRule) -> CursorRule {
+    let mut cursor = cursor_rule_for(agentsync_rule);
+    cursor.globs =
+        apply_exclusions(&cursor.globs, &agentsync_rule.exclude_globs, Tool::Cursor).globs;
+    cursor
+}
+
+/// Cursor rule for `agentsync_rule`, before `excludeGlobs` are applied
+fn cursor_rule_for(agentsync_rule: &AgentSyncRule) -> CursorRule {
     let cursor_config = agentsync_rule.cursor.as_ref();
+
+    if let Some(activation) = agentsync_rule.activation {
+        let globs = activation_globs(
+            agentsync_rule,
+            Tool::Cursor,
+            cursor_config.map(|c| c.globs.as_str()),
+        );
+        let description = agentsync_rule.description.clone();
+        let (description, globs) = match activation {
+            Activation::Always | Activation::Manual => (String::new(), String::new()),
+            Activation::Glob => (String::new(), globs),
+            Activation::ModelDecision => (description, String::new()),
+            Activation::GlobOrModel => (description, globs),
+        };
+        return CursorRule {
+            description,
+            always_apply: activation == Activation::Always,
+            globs,
+        };
+    }
+
     let always_apply = cursor_config.is_some_and(|c| c.always_apply);
 
     // For Always Apply mode, Cursor should not have description or globs in frontmatter
@@ -44,7 +94,7 @@ pub fn agentsync_to_cursor(agentsync_rule: &AgentSyncRule) -> CursorRule {
                 if is_universal_glob(&agentsync_rule.globs) {
                     String::new()
                 } else {
-                    normalize_globs(&agentsync_rule.globs)
+                    top_level_globs(agentsync_rule, Tool::Cursor)
                 }
             },
             |c| normalize_globs(&c.globs),
@@ -77,6 +127,66 @@ pub fn agentsync_rule_to_cursor(rule: &Rule<AgentSyncRule>) -> Rule<CursorRule>
     }
 }
 
+/// Fields of an `AgentSync` rule that Cursor drops or fills with defaults
+#[must_use]
+pub fn cursor_notes(agentsync_rule: &AgentSyncRule) -> Vec<ConversionNote> {
+    let mut notes = Vec::new();
+
+    if let Some(activation) = agentsync_rule.activation {
+        let cursor = agentsync_to_cursor(agentsync_rule);
+        if !agentsync_rule.description.is_empty() && cursor.description.is_empty() {
+            notes.push(ConversionNote::dropped(
+                "description",
+                format!("`description` dropped: Cursor omits it for `{activation}` rules"),
+            ));
+        }
+        let has_globs = !is_universal_glob(&agentsync_rule.globs)
+            || agentsync_rule
+                .windsurf
+                .as_ref()
+                .is_some_and(|w| !w.globs.is_empty());
+        if has_globs && cursor.globs.is_empty() {
+            notes.push(ConversionNote::dropped(
+                "globs",
+                format!("`globs` dropped: Cursor omits them for `{activation}` rules"),
+            ));
+        }
+        return notes;
+    }
+
+    match agentsync_rule.cursor.as_ref() {
+        None => {
+            notes.push(ConversionNote::defaulted(
+                "cursor.alwaysApply",
+                "`cursor.alwaysApply` defaulted to false (no `cursor` section)",
+            ));
+            if !is_universal_glob(&agentsync_rule.globs) {
+                notes.push(ConversionNote::defaulted(
+                    "cursor.globs",
+                    "`cursor.globs` defaulted to the top-level `globs`",
+                ));
+            }
+        }
+        Some(config) if config.always_apply => {
+            if !agentsync_rule.description.is_empty() {
+                notes.push(ConversionNote::dropped(
+                    "description",
+                    "`description` dropped: Cursor omits it for always-applied rules",
+                ));
+            }
+            if !config.globs.is_empty() {
+                notes.push(ConversionNote::dropped(
+                    "cursor.globs",
+                    "`cursor.globs` dropped: Cursor omits globs for always-applied rules",
+                ));
+            }
+        }
+        Some(_) => {}
+    }
+
+    notes
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
@@ -156,13 +266,17 @@ mod tests {
         let agentsync = AgentSyncRule {
             targets: vec!["*".to_string()],
             description: "Test rule".to_string(),
+            tags: Vec::new(),
+            activation: None,
             globs: "**/*.rs".to_string(),
+            exclude_globs: String::new(),
             cursor: Some(CursorConfig {
                 always_apply: false,
                 globs: "**/*.rs".to_string(),
             }),
             windsurf: None,
             copilot: None,
+            expect: None,
         };
 
         let cursor = agentsync_to_cursor(&agentsync);
@@ -177,10 +291,14 @@ mod tests {
         let agentsync = AgentSyncRule {
             targets: vec!["*".to_string()],
             description: "Test rule".to_string(),
+            tags: Vec::new(),
+            activation: None,
             globs: "**/*.rs".to_string(),
+            exclude_globs: String::new(),
             cursor: None,
             windsurf: None,
             copilot: None,
+            expect: None,
         };
 
         let cursor = agentsync_to_cursor(&agentsync);
@@ -213,13 +331,17 @@ mod tests {
             frontmatter: AgentSyncRule {
                 targets: vec!["*".to_string()],
                 description: "Test rule".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.rs".to_string(),
+                exclude_globs: String::new(),
                 cursor: Some(CursorConfig {
                     always_apply: false,
                     globs: "**/*.rs".to_string(),
                 }),
                 windsurf: None,
                 copilot: None,
+                expect: None,
             },
             content: "# Test Content\n\nRule body here.".to_string(),
         };
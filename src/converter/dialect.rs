@@ -0,0 +1,404 @@
+//! Rewrite glob lists from one tool's dialect to another's.
+//!
+//! Top-level `globs` are written in the `AgentSync` dialect and tool sections in
+//! their tool's own (see [`crate::globs`]). Moving patterns between them keeps
+//! what they match rather than their text:
+//!
+//! | Pattern        | Read as                  | Cursor, Windsurf, `AgentSync` | Copilot    |
+//! |----------------|--------------------------|-------------------------------|------------|
+//! | `*.ts`         | file name at any depth   | `*.ts`                        | `**/*.ts`  |
+//! | `/*.ts`        | project root only        | `/*.ts`                       | `*.ts`     |
+//! | `/src/*.ts`    | relative to the root     | `/src/*.ts`                   | `src/*.ts` |
+//! | `docs/`        | everything under `docs/` | `docs/`                       | `docs/**`  |
+//! | `!**/*.gen.ts` | excluded                 | kept by Cursor only           | dropped    |
+//!
+//! A bare pattern from Copilot means the project root only, so it gains a
+//! leading `/` in the other dialects. GitHub's documentation for `applyTo`
+//! reads `*.py` as the `.py` files in the current directory, the repository
+//! root, and uses `**/*.py` for every directory
+//! (<https://docs.github.com/en/copilot/customizing-copilot/adding-repository-custom-instructions-for-github-copilot>).
+//! Patterns that can't be expressed in the target dialect are dropped and
+//! reported.
+//!
+//! Importing keeps `!` patterns as `excludeGlobs`, including from Windsurf and
+//! Copilot, which don't support them but may still have them written in.
+//! `excludeGlobs` become `!` patterns where the dialect has them (Cursor).
+//! Elsewhere the include list is narrowed instead: alternatives an exclusion
+//! covers are removed (`**/*.{ts,tsx}` minus `**/*.tsx` is `**/*.ts`), and
+//! exclusions that can't overlap the includes need nothing. Anything else can't
+//! be expressed and is reported.
+
+use super::normalize_globs;
+use crate::fs::Tool;
+use crate::globs::{Dialect, expand_braces, split_patterns};
+
+/// Characters that start a wildcard or class in a glob
+const WILDCARDS: &[char] = &['*', '?', '[', '{'];
+
+/// A glob list rewritten for another tool
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlobTranslation {
+    pub globs: String,
+    /// Patterns the target can't express, with the reason; left out of `globs`
+    pub dropped: Vec<(String, String)>,
+}
+
+/// Rewrite `globs`, read as `from` reads them, so that `to` matches the same files
+#[must_use]
+pub fn translate_globs(globs: &str, from: Tool, to: Tool) -> GlobTranslation {
+    if from == to {
+        return GlobTranslation {
+            globs: normalize_globs(globs),
+            dropped: Vec::new(),
+        };
+    }
+
+    let (from, to) = (Dialect::of(from), Dialect::of(to));
+    let mut patterns = Vec::new();
+    let mut dropped = Vec::new();
+    for source in split_patterns(globs) {
+        match translate_pattern(source, from, to) {
+            Ok(pattern) => patterns.push(pattern),
+            Err(reason) => dropped.push((source.to_string(), reason)),
+        }
+    }
+
+    GlobTranslation {
+        globs: patterns.join(","),
+        dropped,
+    }
+}
+
+fn translate_pattern(source: &str, from: Dialect, to: Dialect) -> Result<String, String> {
+    let (negation, pattern) = match source.strip_prefix('!') {
+        Some(rest) => ("!", rest),
+        None => ("", source),
+    };
+    if !negation.is_empty() && !to.negation {
+        return Err("`!` exclusions are not supported".to_string());
+    }
+
+    let leading_slash = pattern.starts_with('/');
+    let mut body = pattern.trim_start_matches('/').to_string();
+    // Any `/`, including a trailing one, ties the pattern to the project root
+    let rooted = body.contains('/');
+    let floating = !rooted && !leading_slash && from.basename_patterns;
+
+    if body.ends_with('/') && !to.directory_patterns {
+        body.push_str("**");
+    }
+
+    let rewritten = if !rooted && body.starts_with("**") {
+        // Matches at any depth in every dialect
+        body
+    } else if floating {
+        if to.basename_patterns {
+            body
+        } else {
+            format!("**/{body}")
+        }
+    } else if to.basename_patterns && (leading_slash || !rooted) {
+        // A leading `/` anchors the pattern; only needed where bare names float
+        format!("/{body}")
+    } else {
+        body
+    };
+
+    Ok(format!("{negation}{rewritten}"))
+}
+
+/// Take `excludes` (`AgentSync` dialect) out of `globs` (`tool`'s dialect).
+///
+/// A leading `!` on an exclusion is redundant and ignored.
+#[must_use]
+pub fn apply_exclusions(globs: &str, excludes: &str, tool: Tool) -> GlobTranslation {
+    let includes = split_patterns(globs);
+    let excludes: Vec<&str> = split_patterns(excludes)
+        .into_iter()
+        .map(|exclude| exclude.trim_start_matches('!'))
+        .collect();
+    if includes.is_empty() || excludes.is_empty() {
+        return GlobTranslation {
+            globs: normalize_globs(globs),
+            dropped: Vec::new(),
+        };
+    }
+
+    let source = Dialect::of(Tool::AgentSync);
+    let dialect = Dialect::of(tool);
+    if dialect.negation {
+        let mut patterns: Vec<String> = includes.iter().map(ToString::to_string).collect();
+        for exclude in excludes {
+            if let Ok(pattern) = translate_pattern(exclude, source, dialect) {
+                let negated = format!("!{pattern}");
+                if !patterns.contains(&negated) {
+                    patterns.push(negated);
+                }
+            }
+        }
+        return GlobTranslation {
+            globs: patterns.join(","),
+            dropped: Vec::new(),
+        };
+    }
+
+    let exclude_paths: Vec<Vec<String>> = excludes
+        .iter()
+        .map(|exclude| {
+            expand_braces(exclude)
+                .iter()
+                .map(|alternative| full_path(alternative, source))
+                .collect()
+        })
+        .collect();
+
+    // Remove include alternatives that some exclusion covers entirely
+    let mut patterns = Vec::new();
+    for include in includes {
+        let alternatives = expand_braces(include);
+        let remaining: Vec<String> = alternatives
+            .iter()
+            .filter(|alternative| {
+                let path = full_path(alternative, dialect);
+                !exclude_paths
+                    .iter()
+                    .flatten()
+                    .any(|exclude| covers(exclude, &path))
+            })
+            .cloned()
+            .collect();
+        if remaining.len() == alternatives.len() {
+            patterns.push(include.to_string());
+        } else {
+            patterns.extend(remaining);
+        }
+    }
+
+    // What's left must not overlap any exclusion
+    let include_paths: Vec<String> = patterns
+        .iter()
+        .flat_map(|pattern| expand_braces(pattern))
+        .map(|alternative| full_path(&alternative, dialect))
+        .collect();
+    let dropped = excludes
+        .iter()
+        .zip(&exclude_paths)
+        .filter(|(_, alternatives)| {
+            !alternatives.iter().all(|exclude| {
+                include_paths
+                    .iter()
+                    .all(|include| are_disjoint(include, exclude))
+            })
+        })
+        .map(|(exclude, _)| {
+            (
+                (*exclude).to_string(),
+                "it can't be subtracted from the globs without `!` patterns".to_string(),
+            )
+        })
+        .collect();
+
+    GlobTranslation {
+        globs: patterns.join(","),
+        dropped,
+    }
+}
+
+/// `globs` without their `!` patterns
+#[must_use]
+pub fn included_patterns(globs: &str) -> String {
+    split_patterns(globs)
+        .into_iter()
+        .filter(|pattern| !pattern.starts_with('!'))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The `!` patterns of `globs` (in `from`'s dialect) as `excludeGlobs`
+#[must_use]
+pub fn negated_patterns(globs: &str, from: Tool) -> String {
+    let (from, to) = (Dialect::of(from), Dialect::of(Tool::AgentSync));
+    split_patterns(globs)
+        .into_iter()
+        .filter_map(|pattern| pattern.strip_prefix('!'))
+        .filter_map(|pattern| translate_pattern(pattern, from, to).ok())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// `pattern` as a glob over whole project-relative paths, the way `dialect` reads it
+fn full_path(pattern: &str, dialect: Dialect) -> String {
+    let mut pattern = pattern.to_string();
+    if pattern.ends_with('/') {
+        pattern.push_str("**");
+    }
+    let anchored = pattern.starts_with('/');
+    let pattern = pattern.trim_start_matches('/');
+    if !anchored && dialect.basename_patterns && !pattern.contains('/') {
+        format!("**/{pattern}")
+    } else {
+        pattern.to_string()
+    }
+}
+
+/// Whether every path `include` matches is also matched by `exclude` (both full paths).
+///
+/// Only recognizes the common shapes: identical patterns, `**`, a directory
+/// (`dir/**`), and a file name at any depth (`**/*.gen.ts`).
+fn covers(exclude: &str, include: &str) -> bool {
+    if exclude == include || exclude == "**" {
+        return true;
+    }
+    if let Some(directory) = exclude.strip_suffix("**")
+        && directory.ends_with('/')
+        && !directory.contains(WILDCARDS)
+        && include.starts_with(directory)
+    {
+        return true;
+    }
+    exclude
+        .strip_prefix("**/")
+        .is_some_and(|name| !name.contains('/') && include.rsplit('/').next() == Some(name))
+}
+
+/// Whether no path can match both full-path patterns, judged by their literal
+/// start and end
+fn are_disjoint(a: &str, b: &str) -> bool {
+    let (a_prefix, b_prefix) = (literal_prefix(a), literal_prefix(b));
+    let (a_suffix, b_suffix) = (literal_suffix(a), literal_suffix(b));
+    !(a_prefix.starts_with(b_prefix) || b_prefix.starts_with(a_prefix))
+        || !(a_suffix.ends_with(b_suffix) || b_suffix.ends_with(a_suffix))
+}
+
+/// Text before the first wildcard
+fn literal_prefix(pattern: &str) -> &str {
+    pattern.split(WILDCARDS).next().unwrap_or_default()
+}
+
+/// Text after the last wildcard
+fn literal_suffix(pattern: &str) -> &str {
+    pattern
+        .rsplit(['*', '?', ']', '}'])
+        .next()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translated(globs: &str, from: Tool, to: Tool) -> String {
+        translate_globs(globs, from, to).globs
+    }
+
+    #[test]
+    fn test_bare_patterns() {
+        assert_eq!(
+            translated("*.ts", Tool::AgentSync, Tool::Copilot),
+            "**/*.ts"
+        );
+        assert_eq!(translated("*.ts", Tool::AgentSync, Tool::Cursor), "*.ts");
+        assert_eq!(translated("*.ts", Tool::Copilot, Tool::AgentSync), "/*.ts");
+        assert_eq!(translated("*.ts", Tool::Copilot, Tool::Windsurf), "/*.ts");
+        assert_eq!(translated("/*.ts", Tool::Windsurf, Tool::Copilot), "*.ts");
+        assert_eq!(translated("**", Tool::AgentSync, Tool::Copilot), "**");
+    }
+
+    #[test]
+    fn test_rooted_and_directory_patterns() {
+        assert_eq!(
+            translated("/src/*.ts,src/**/*.rs", Tool::AgentSync, Tool::Copilot),
+            "src/*.ts,src/**/*.rs"
+        );
+        assert_eq!(
+            translated("/src/*.ts", Tool::AgentSync, Tool::Cursor),
+            "/src/*.ts"
+        );
+        assert_eq!(
+            translated("docs/", Tool::AgentSync, Tool::Copilot),
+            "docs/**"
+        );
+        assert_eq!(translated("docs/", Tool::Cursor, Tool::Windsurf), "docs/");
+        assert_eq!(
+            translated("src/**/*.{ts,tsx}", Tool::Cursor, Tool::Copilot),
+            "src/**/*.{ts,tsx}"
+        );
+    }
+
+    #[test]
+    fn test_negation_dropped_where_unsupported() {
+        let globs = "**/*.ts, !**/*.gen.ts";
+        assert_eq!(
+            translated(globs, Tool::AgentSync, Tool::Cursor),
+            "**/*.ts,!**/*.gen.ts"
+        );
+
+        let windsurf = translate_globs(globs, Tool::Cursor, Tool::Windsurf);
+        assert_eq!(windsurf.globs, "**/*.ts");
+        assert_eq!(
+            windsurf.dropped,
+            [(
+                "!**/*.gen.ts".to_string(),
+                "`!` exclusions are not supported".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_translations_match_the_same_files() {
+        use crate::globs::GlobMatcher;
+
+        let paths = [
+            "index.ts",
+            "src/index.ts",
+            "src/app/view.ts",
+            "docs/guide/intro.md",
+        ];
+        for globs in ["*.ts", "/*.ts", "/src/*.ts", "docs/", "src/**/*.ts"] {
+            let source = GlobMatcher::new(globs, Dialect::of(Tool::AgentSync));
+            for tool in [Tool::Cursor, Tool::Windsurf, Tool::Copilot] {
+                let target =
+                    GlobMatcher::new(&translated(globs, Tool::AgentSync, tool), Dialect::of(tool));
+                for path in paths {
+                    assert_eq!(
+                        source.matches(path),
+                        target.matches(path),
+                        "{globs} in {tool} for {path}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exclusions() {
+        // A directory exclusion covers everything under it
+        let copilot = apply_exclusions("src/gen/**/*.ts,lib/**/*.rs", "src/gen/", Tool::Copilot);
+        assert_eq!(copilot.globs, "lib/**/*.rs");
+        assert!(copilot.dropped.is_empty());
+
+        // Bare patterns are read as file names at any depth
+        let windsurf = apply_exclusions("**/*.ts", "*.gen.ts", Tool::Windsurf);
+        assert_eq!(windsurf.globs, "**/*.ts");
+        assert_eq!(windsurf.dropped[0].0, "*.gen.ts");
+
+        assert_eq!(
+            apply_exclusions("**/*.ts,!x", "*.gen.ts, !x", Tool::Cursor).globs,
+            "**/*.ts,!x,!*.gen.ts"
+        );
+        assert_eq!(
+            apply_exclusions("**/*.ts", "", Tool::Cursor).globs,
+            "**/*.ts"
+        );
+    }
+
+    #[test]
+    fn test_negated_patterns() {
+        assert_eq!(
+            negated_patterns("src/**,!src/gen/,!*.snap", Tool::Cursor),
+            "src/gen/,*.snap"
+        );
+        assert_eq!(negated_patterns("**/*.ts", Tool::Cursor), "");
+        assert_eq!(included_patterns("src/**, !src/gen/,!*.snap"), "src/**");
+    }
+}
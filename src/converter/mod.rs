@@ -1,23 +1,51 @@
 //! Bidirectional conversion between AgentSync and tool formats.
+//!
+//! When a rule sets `activation`, every tool's mode is derived from it:
+//!
+//! | `activation`     | Cursor                       | Windsurf                  | Copilot                    |
+//! |------------------|------------------------------|---------------------------|----------------------------|
+//! | `always`         | `alwaysApply: true`          | `trigger: always_on`      | `applyTo: "**"`            |
+//! | `glob`           | `globs`, no `description`    | `trigger: glob`           | `applyTo: <globs>`         |
+//! | `model_decision` | `description`, no `globs`    | `trigger: model_decision` | `applyTo: "**"` (lossy)    |
+//! | `manual`         | no `description`, no `globs` | `trigger: manual`         | `applyTo: "**"` (lossy)    |
+//! | `glob_or_model`  | `globs` and `description`    | `trigger: glob` (lossy)   | `applyTo: <globs>` (lossy) |
+//!
+//! Importing reads the same table backwards, so a tool rule converted to
+//! `AgentSync` and back comes out unchanged. Without `activation`, each tool's
+//! section (`cursor`, `windsurf`, `copilot`) decides that tool's mode.
+//!
+//! Top-level `globs` are rewritten for each tool's glob dialect (see
+//! [`dialect`]); globs in a tool's section are already in its dialect and are
+//! copied as they are.
 
+use crate::fs::Tool;
+use crate::globs::Dialect;
 use crate::models::{CopilotConfig, CursorConfig, WindsurfConfig, WindsurfTrigger};
 use itertools::Itertools;
+use serde::Serialize;
 
 mod copilot;
 mod cursor;
+pub mod dialect;
 mod windsurf;
 
+pub use crate::models::{Activation, AgentSyncRule};
+
 // Re-export conversion functions
 pub use copilot::{
-    agentsync_rule_to_copilot, agentsync_to_copilot, copilot_rule_to_agentsync,
-    copilot_to_agentsync,
+    agentsync_rule_to_copilot, agentsync_to_copilot, copilot_activation, copilot_notes,
+    copilot_rule_to_agentsync, copilot_to_agentsync,
 };
 pub use cursor::{
-    agentsync_rule_to_cursor, agentsync_to_cursor, cursor_rule_to_agentsync, cursor_to_agentsync,
+    agentsync_rule_to_cursor, agentsync_to_cursor, cursor_activation, cursor_notes,
+    cursor_rule_to_agentsync, cursor_to_agentsync,
+};
+pub use dialect::{
+    GlobTranslation, apply_exclusions, included_patterns, negated_patterns, translate_globs,
 };
 pub use windsurf::{
-    agentsync_rule_to_windsurf, agentsync_to_windsurf, windsurf_rule_to_agentsync,
-    windsurf_to_agentsync,
+    agentsync_rule_to_windsurf, agentsync_to_windsurf, windsurf_activation, windsurf_notes,
+    windsurf_rule_to_agentsync, windsurf_to_agentsync,
 };
 
 // Common constants
@@ -42,6 +70,295 @@ pub(crate) fn is_universal_glob(globs: &str) -> bool {
         || normalized == GLOB_UNIVERSAL_DOUBLE_STAR
 }
 
+/// Top-level globs rewritten in `tool`'s dialect
+pub(crate) fn top_level_globs(rule: &AgentSyncRule, tool: Tool) -> String {
+    translate_globs(&rule.globs, Tool::AgentSync, tool).globs
+}
+
+/// Globs a tool gets under an explicit activation: its own section's, else the
+/// top-level ones unless they match everything
+pub(crate) fn activation_globs(
+    rule: &AgentSyncRule,
+    tool: Tool,
+    section_globs: Option<&str>,
+) -> String {
+    match section_globs.map(normalize_globs) {
+        Some(globs) if !is_universal_glob(&globs) => globs,
+        _ if is_universal_glob(&rule.globs) => String::new(),
+        _ => top_level_globs(rule, tool),
+    }
+}
+
+/// Activation the rule asks for, before any tool's limitations apply.
+///
+/// An explicit `activation` always wins. Otherwise Windsurf, which can express
+/// every single-mode activation, is authoritative when it has a section. Without
+/// one, an always-on or glob Cursor section decides, then specific top-level globs,
+/// and otherwise the rule gets the same default Windsurf would give it.
+#[must_use]
+pub fn intended_activation(rule: &AgentSyncRule) -> Activation {
+    if let Some(activation) = requested_activation(rule) {
+        return activation;
+    }
+    if !is_universal_glob(&rule.globs) {
+        return Activation::Glob;
+    }
+    windsurf_activation(&agentsync_to_windsurf(rule))
+}
+
+/// Activation set by `activation` or a tool section, rather than inferred from
+/// the top-level fields
+fn requested_activation(rule: &AgentSyncRule) -> Option<Activation> {
+    if let Some(activation) = rule.activation {
+        return Some(activation);
+    }
+    if rule.windsurf.is_some() {
+        return Some(windsurf_activation(&agentsync_to_windsurf(rule)));
+    }
+    rule.cursor
+        .as_ref()
+        .filter(|cursor| cursor.always_apply || !cursor.globs.is_empty())
+        .map(|_| cursor_activation(&agentsync_to_cursor(rule)))
+}
+
+/// Activation and globs `tool` ends up with after converting `rule`
+#[must_use]
+pub fn converted_activation(rule: &AgentSyncRule, tool: Tool) -> Option<(Activation, String)> {
+    match tool {
+        Tool::AgentSync => None,
+        Tool::Cursor => {
+            let cursor = agentsync_to_cursor(rule);
+            Some((cursor_activation(&cursor), cursor.globs))
+        }
+        Tool::Windsurf => {
+            let windsurf = agentsync_to_windsurf(rule);
+            Some((windsurf_activation(&windsurf), windsurf.globs))
+        }
+        Tool::Copilot => {
+            let copilot = agentsync_to_copilot(rule);
+            let activation = copilot_activation(&copilot);
+            let globs = if activation == Activation::Glob {
+                copilot.apply_to
+            } else {
+                String::new()
+            };
+            Some((activation, globs))
+        }
+    }
+}
+
+/// Something a tool's format can't express about a rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FidelityIssue {
+    /// The tool loads the rule with a different activation than intended
+    ActivationApproximated {
+        intended: Activation,
+        actual: Activation,
+    },
+    /// A top-level glob has no equivalent in the tool's dialect and was dropped
+    GlobDropped { pattern: String, reason: String },
+    /// An `excludeGlobs` pattern the tool's globs can't leave out
+    ExclusionIgnored { pattern: String, reason: String },
+}
+
+/// A lossy conversion of a rule to one tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FidelityWarning {
+    pub tool: Tool,
+    pub issue: FidelityIssue,
+}
+
+impl std::fmt::Display for FidelityWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.issue {
+            FidelityIssue::ActivationApproximated { intended, actual } => write!(
+                f,
+                "{} loads this `{intended}` rule as `{actual}`",
+                self.tool
+            ),
+            FidelityIssue::GlobDropped { pattern, reason } => {
+                write!(f, "{} drops glob `{pattern}`: {reason}", self.tool)
+            }
+            FidelityIssue::ExclusionIgnored { pattern, reason } => write!(
+                f,
+                "{} still loads this rule for files matching excluded `{pattern}`: {reason}",
+                self.tool
+            ),
+        }
+    }
+}
+
+impl FidelityIssue {
+    /// The `AgentSync` field the tool can't express
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::ActivationApproximated { .. } => "activation",
+            Self::GlobDropped { .. } => "globs",
+            Self::ExclusionIgnored { .. } => "excludeGlobs",
+        }
+    }
+}
+
+/// What a conversion does with a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteReason {
+    /// The tool has nowhere to keep `field`
+    Dropped { field: &'static str },
+    /// `field` of a missing tool section is filled in with a default
+    Defaulted { field: &'static str },
+    /// The tool can't represent the rule faithfully
+    Approximated(FidelityIssue),
+}
+
+impl NoteReason {
+    /// The affected field, e.g. `description` or `cursor.globs`
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::Dropped { field } | Self::Defaulted { field } => field,
+            Self::Approximated(issue) => issue.field(),
+        }
+    }
+}
+
+/// Something dropped, defaulted or approximated when converting a rule to a tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionNote {
+    pub reason: NoteReason,
+    pub message: String,
+}
+
+impl ConversionNote {
+    pub(crate) fn dropped(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            reason: NoteReason::Dropped { field },
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn defaulted(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            reason: NoteReason::Defaulted { field },
+            message: message.into(),
+        }
+    }
+}
+
+impl From<FidelityWarning> for ConversionNote {
+    fn from(warning: FidelityWarning) -> Self {
+        Self {
+            message: warning.to_string(),
+            reason: NoteReason::Approximated(warning.issue),
+        }
+    }
+}
+
+impl std::fmt::Display for ConversionNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Ways converting `rule` to `tool` loses meaning
+#[must_use]
+pub fn fidelity_warnings(rule: &AgentSyncRule, tool: Tool) -> Vec<FidelityWarning> {
+    let Some((actual, globs)) = converted_activation(rule, tool) else {
+        return Vec::new();
+    };
+    let intended = intended_activation(rule);
+    let mut warnings = Vec::new();
+
+    // Specific globs alone don't ask for `glob` over `glob_or_model`: Cursor
+    // keeping a description written next to them is not a loss
+    let inferred_from_globs = requested_activation(rule).is_none() && intended == Activation::Glob;
+    if actual != intended && !(inferred_from_globs && actual == Activation::GlobOrModel) {
+        warnings.push(FidelityWarning {
+            tool,
+            issue: FidelityIssue::ActivationApproximated { intended, actual },
+        });
+    }
+
+    if !actual.uses_globs() {
+        return warnings;
+    }
+
+    // Only the top-level globs are translated; a tool section is used as written
+    let translation = translate_globs(&rule.globs, Tool::AgentSync, tool);
+    if globs == apply_exclusions(&translation.globs, &rule.exclude_globs, tool).globs {
+        warnings.extend(
+            translation
+                .dropped
+                .into_iter()
+                .map(|(pattern, reason)| FidelityWarning {
+                    tool,
+                    issue: FidelityIssue::GlobDropped { pattern, reason },
+                }),
+        );
+    }
+
+    // Exclusions are already applied to `globs`; applying them again finds the same leftovers
+    warnings.extend(
+        apply_exclusions(&globs, &rule.exclude_globs, tool)
+            .dropped
+            .into_iter()
+            .map(|(pattern, reason)| FidelityWarning {
+                tool,
+                issue: FidelityIssue::ExclusionIgnored { pattern, reason },
+            }),
+    );
+
+    warnings
+}
+
+/// Fields of an `AgentSync` rule that `tool` drops or fills with defaults when converting
+#[must_use]
+pub fn conversion_notes(rule: &AgentSyncRule, tool: Tool) -> Vec<ConversionNote> {
+    let mut notes = match tool {
+        Tool::AgentSync => return Vec::new(),
+        Tool::Cursor => cursor_notes(rule),
+        Tool::Windsurf => windsurf_notes(rule),
+        Tool::Copilot => copilot_notes(rule),
+    };
+
+    if !rule.tags.is_empty() {
+        notes.push(ConversionNote::dropped(
+            "tags",
+            format!("`tags` dropped: {tool} rules have no tags"),
+        ));
+    }
+    if rule.targets.iter().any(|target| target != TARGET_ALL) {
+        notes.push(ConversionNote::dropped(
+            "targets",
+            format!("`targets` dropped: {tool} rules have no targets, so an imported rule targets every tool"),
+        ));
+    }
+    if !rule.exclude_globs.is_empty() {
+        let uses_globs =
+            converted_activation(rule, tool).is_some_and(|(activation, _)| activation.uses_globs());
+        if !uses_globs {
+            notes.push(ConversionNote::dropped(
+                "excludeGlobs",
+                format!("`excludeGlobs` dropped: {tool} doesn't attach this rule by globs"),
+            ));
+        } else if !Dialect::of(tool).negation {
+            notes.push(ConversionNote::dropped(
+                "excludeGlobs",
+                format!("`excludeGlobs` dropped: {tool} globs have no `!` patterns, so exclusions are taken out of the globs instead"),
+            ));
+        }
+    }
+
+    notes.extend(
+        fidelity_warnings(rule, tool)
+            .into_iter()
+            .map(ConversionNote::from),
+    );
+
+    notes
+}
+
 /// Unified configuration mode
 #[derive(Debug, Clone)]
 pub(crate) enum ConfigMode<'a> {
@@ -49,11 +366,37 @@ pub(crate) enum ConfigMode<'a> {
     Manual,
     Intelligent,
     Glob(&'a str),
+    /// Glob attachment plus agent-requested by description (Cursor only)
+    GlobOrModel(&'a str),
+}
+
+impl<'a> ConfigMode<'a> {
+    /// Mode for an activation; `globs` is only used by glob-based activations
+    pub(crate) const fn from_activation(activation: Activation, globs: &'a str) -> Self {
+        match activation {
+            Activation::Always => Self::AlwaysOn,
+            Activation::Glob => Self::Glob(globs),
+            Activation::ModelDecision => Self::Intelligent,
+            Activation::Manual => Self::Manual,
+            Activation::GlobOrModel => Self::GlobOrModel(globs),
+        }
+    }
+
+    pub(crate) const fn activation(&self) -> Activation {
+        match self {
+            Self::AlwaysOn => Activation::Always,
+            Self::Manual => Activation::Manual,
+            Self::Intelligent => Activation::ModelDecision,
+            Self::Glob(_) => Activation::Glob,
+            Self::GlobOrModel(_) => Activation::GlobOrModel,
+        }
+    }
 }
 
-/// Create tool configs from unified mode
+/// Create tool configs from unified mode, with globs written in `from`'s dialect
 pub(crate) fn create_all_configs(
     mode: &ConfigMode<'_>,
+    from: Tool,
 ) -> (CursorConfig, WindsurfConfig, CopilotConfig, String) {
     match mode {
         ConfigMode::AlwaysOn => (
@@ -98,21 +441,21 @@ pub(crate) fn create_all_configs(
             },
             GLOB_UNIVERSAL_RECURSIVE.to_string(),
         ),
-        ConfigMode::Glob(globs) => {
-            let normalized = normalize_globs(globs);
+        ConfigMode::Glob(globs) | ConfigMode::GlobOrModel(globs) => {
+            let translated = |to| translate_globs(globs, from, to).globs;
             (
                 CursorConfig {
                     always_apply: false,
-                    globs: normalized.clone(),
+                    globs: translated(Tool::Cursor),
                 },
                 WindsurfConfig {
                     trigger: WindsurfTrigger::Glob,
-                    globs: normalized.clone(),
+                    globs: translated(Tool::Windsurf),
                 },
                 CopilotConfig {
-                    apply_to: normalized.clone(),
+                    apply_to: translated(Tool::Copilot),
                 },
-                normalized,
+                translated(Tool::AgentSync),
             )
         }
     }
@@ -157,4 +500,206 @@ mod tests {
         assert!(!is_universal_glob("**/*.py"));
         assert!(!is_universal_glob("src/**/*"));
     }
+
+    fn rule_with(
+        windsurf: Option<WindsurfTrigger>,
+        description: &str,
+        globs: &str,
+    ) -> AgentSyncRule {
+        AgentSyncRule {
+            targets: vec![TARGET_ALL.to_string()],
+            description: description.to_string(),
+            tags: Vec::new(),
+            activation: None,
+            globs: globs.to_string(),
+            exclude_globs: String::new(),
+            cursor: None,
+            windsurf: windsurf.map(|trigger| WindsurfConfig {
+                trigger,
+                globs: String::new(),
+            }),
+            copilot: None,
+            expect: None,
+        }
+    }
+
+    #[test]
+    fn test_fidelity_manual_rule_becomes_always_on_in_copilot() {
+        let rule = rule_with(Some(WindsurfTrigger::Manual), "", "**/*");
+
+        assert!(fidelity_warnings(&rule, Tool::Windsurf).is_empty());
+        assert!(fidelity_warnings(&rule, Tool::Cursor).is_empty());
+        assert_eq!(
+            fidelity_warnings(&rule, Tool::Copilot),
+            [FidelityWarning {
+                tool: Tool::Copilot,
+                issue: FidelityIssue::ActivationApproximated {
+                    intended: Activation::Manual,
+                    actual: Activation::Always,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_fidelity_cursor_glob_rule_with_description() {
+        let mut rule = rule_with(None, "Python style", "**/*.py");
+
+        // Without `activation`, Cursor keeps the description and also lets the agent
+        // request it, which the rule as written doesn't rule out
+        assert_eq!(intended_activation(&rule), Activation::Glob);
+        assert_eq!(
+            converted_activation(&rule, Tool::Cursor).map(|(activation, _)| activation),
+            Some(Activation::GlobOrModel)
+        );
+        assert!(fidelity_warnings(&rule, Tool::Cursor).is_empty());
+        assert!(fidelity_warnings(&rule, Tool::Copilot).is_empty());
+
+        rule.activation = Some(Activation::Glob);
+        assert!(fidelity_warnings(&rule, Tool::Cursor).is_empty());
+        assert!(agentsync_to_cursor(&rule).description.is_empty());
+
+        rule.activation = Some(Activation::GlobOrModel);
+        assert!(fidelity_warnings(&rule, Tool::Cursor).is_empty());
+        assert_eq!(fidelity_warnings(&rule, Tool::Windsurf).len(), 1);
+
+        // Asking for `glob` in the Windsurf section is explicit
+        rule.activation = None;
+        rule.windsurf = Some(WindsurfConfig {
+            trigger: WindsurfTrigger::Glob,
+            globs: String::new(),
+        });
+        assert_eq!(
+            fidelity_warnings(&rule, Tool::Cursor)[0].issue,
+            FidelityIssue::ActivationApproximated {
+                intended: Activation::Glob,
+                actual: Activation::GlobOrModel,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fidelity_manual_rule_with_description_in_cursor() {
+        let mut rule = rule_with(Some(WindsurfTrigger::Manual), "Review checklist", "**/*");
+
+        let warnings = fidelity_warnings(&rule, Tool::Cursor);
+        assert_eq!(
+            warnings[0].to_string(),
+            "cursor loads this `manual` rule as `model_decision`"
+        );
+
+        rule.activation = Some(Activation::Manual);
+        assert!(fidelity_warnings(&rule, Tool::Cursor).is_empty());
+    }
+
+    #[test]
+    fn test_top_level_globs_translated_per_tool() {
+        let mut rule = rule_with(None, "", "*.ts, !**/*.gen.ts");
+        rule.activation = Some(Activation::Glob);
+
+        assert_eq!(agentsync_to_cursor(&rule).globs, "*.ts,!**/*.gen.ts");
+        assert_eq!(agentsync_to_copilot(&rule).apply_to, "**/*.ts");
+        assert!(fidelity_warnings(&rule, Tool::Cursor).is_empty());
+        assert_eq!(
+            fidelity_warnings(&rule, Tool::Copilot)[0].to_string(),
+            "copilot drops glob `!**/*.gen.ts`: `!` exclusions are not supported"
+        );
+
+        // A tool section is already in the tool's dialect and used as written
+        rule.copilot = Some(CopilotConfig {
+            apply_to: "*.ts".to_string(),
+        });
+        assert_eq!(agentsync_to_copilot(&rule).apply_to, "*.ts");
+        assert!(fidelity_warnings(&rule, Tool::Copilot).is_empty());
+    }
+
+    #[test]
+    fn test_imported_globs_translated_per_tool() {
+        // Copilot reads a bare `*.md` as the root only (see `dialect`), so it gains a `/`
+        let copilot = copilot_to_agentsync(&crate::models::CopilotRule {
+            description: String::new(),
+            apply_to: "*.md".to_string(),
+        });
+        assert_eq!(copilot.globs, "/*.md");
+        assert_eq!(
+            copilot.cursor.as_ref().map(|c| c.globs.as_str()),
+            Some("/*.md")
+        );
+        assert_eq!(
+            copilot.copilot.as_ref().map(|c| c.apply_to.as_str()),
+            Some("*.md")
+        );
+
+        let cursor = cursor_to_agentsync(&crate::models::CursorRule {
+            description: String::new(),
+            always_apply: false,
+            globs: "**/*.ts,!**/*.gen.ts".to_string(),
+        });
+        assert_eq!(cursor.globs, "**/*.ts");
+        assert_eq!(cursor.exclude_globs, "**/*.gen.ts");
+        assert_eq!(agentsync_to_cursor(&cursor).globs, "**/*.ts,!**/*.gen.ts");
+        assert!(fidelity_warnings(&cursor, Tool::Cursor).is_empty());
+        assert_eq!(
+            fidelity_warnings(&cursor, Tool::Windsurf)[0].issue,
+            FidelityIssue::ExclusionIgnored {
+                pattern: "**/*.gen.ts".to_string(),
+                reason: "it can't be subtracted from the globs without `!` patterns".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_imported_negations_become_exclusions() {
+        let windsurf = windsurf_to_agentsync(&crate::models::WindsurfRule {
+            trigger: WindsurfTrigger::Glob,
+            description: String::new(),
+            globs: "**/*.ts, !**/*.gen.ts".to_string(),
+        });
+        assert_eq!(windsurf.globs, "**/*.ts");
+        assert_eq!(windsurf.exclude_globs, "**/*.gen.ts");
+        assert_eq!(
+            windsurf.windsurf.as_ref().map(|w| w.globs.as_str()),
+            Some("**/*.ts")
+        );
+        assert_eq!(
+            windsurf.cursor.as_ref().map(|c| c.globs.as_str()),
+            Some("**/*.ts")
+        );
+        assert_eq!(agentsync_to_cursor(&windsurf).globs, "**/*.ts,!**/*.gen.ts");
+
+        let copilot = copilot_to_agentsync(&crate::models::CopilotRule {
+            description: String::new(),
+            apply_to: "src/**,!src/gen/**".to_string(),
+        });
+        assert_eq!(copilot.globs, "src/**");
+        assert_eq!(copilot.exclude_globs, "src/gen/**");
+        assert_eq!(
+            copilot.copilot.as_ref().map(|c| c.apply_to.as_str()),
+            Some("src/**")
+        );
+    }
+
+    #[test]
+    fn test_exclusions_narrow_includes_without_negation() {
+        let mut rule = rule_with(None, "", "src/**/*.{ts,tsx},docs/**/*.md");
+        rule.activation = Some(Activation::Glob);
+        rule.exclude_globs = "*.tsx,docs/drafts/**,**/*.py".to_string();
+
+        assert_eq!(
+            agentsync_to_cursor(&rule).globs,
+            "src/**/*.{ts,tsx},docs/**/*.md,!*.tsx,!docs/drafts/**,!**/*.py"
+        );
+        assert_eq!(
+            agentsync_to_copilot(&rule).apply_to,
+            "src/**/*.ts,docs/**/*.md"
+        );
+
+        // `docs/drafts/**` is only part of `docs/**`; `**/*.py` never overlaps
+        let warnings = fidelity_warnings(&rule, Tool::Copilot);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "copilot still loads this rule for files matching excluded `docs/drafts/**`: it can't be subtracted from the globs without `!` patterns"
+        );
+    }
 }
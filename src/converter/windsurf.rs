@@ -1,38 +1,104 @@
 //! Windsurf-specific conversions
 
-use super::{ConfigMode, TARGET_ALL, create_all_configs, is_universal_glob, normalize_globs};
+use super::{
+    Activation, ConfigMode, ConversionNote, TARGET_ALL, activation_globs, apply_exclusions,
+    create_all_configs, included_patterns, is_universal_glob, negated_patterns, normalize_globs,
+    top_level_globs,
+};
+use crate::fs::Tool;
 use crate::models::{AgentSyncRule, Rule, WindsurfRule, WindsurfTrigger};
 
-/// Convert Windsurf rule to `AgentSync` format
-#[must_use]
-pub fn windsurf_to_agentsync(windsurf_rule: &WindsurfRule) -> AgentSyncRule {
-    let mode = match windsurf_rule.trigger {
+fn windsurf_mode(windsurf_rule: &WindsurfRule) -> ConfigMode<'_> {
+    match windsurf_rule.trigger {
         WindsurfTrigger::AlwaysOn => ConfigMode::AlwaysOn,
         WindsurfTrigger::Glob => ConfigMode::Glob(&windsurf_rule.globs),
         WindsurfTrigger::ModelDecision => ConfigMode::Intelligent,
         WindsurfTrigger::Manual => ConfigMode::Manual,
+    }
+}
+
+/// How Windsurf activates this rule
+#[must_use]
+pub fn windsurf_activation(windsurf_rule: &WindsurfRule) -> Activation {
+    windsurf_mode(windsurf_rule).activation()
+}
+
+/// Convert Windsurf rule to `AgentSync` format
+#[must_use]
+pub fn windsurf_to_agentsync(windsurf_rule: &WindsurfRule) -> AgentSyncRule {
+    // Windsurf has no `!` patterns; any written in are kept as the exclusions they were meant as
+    let included = WindsurfRule {
+        globs: included_patterns(&windsurf_rule.globs),
+        ..windsurf_rule.clone()
     };
+    let mode = windsurf_mode(&included);
 
-    let (cursor_config, mut windsurf_config, copilot_config, globs) = create_all_configs(&mode);
+    let (cursor_config, mut windsurf_config, copilot_config, globs) =
+        create_all_configs(&mode, Tool::Windsurf);
 
     // Preserve the original Windsurf trigger mode
     windsurf_config.trigger = windsurf_rule.trigger.clone();
-    windsurf_config.globs = normalize_globs(&windsurf_rule.globs);
+    windsurf_config.globs = normalize_globs(&included.globs);
 
     AgentSyncRule {
         targets: vec![TARGET_ALL.to_string()],
         description: windsurf_rule.description.clone(),
+        tags: Vec::new(),
+        activation: Some(mode.activation()),
         globs,
+        exclude_globs: negated_patterns(&windsurf_rule.globs, Tool::Windsurf),
         cursor: Some(cursor_config),
         windsurf: Some(windsurf_config),
         copilot: Some(copilot_config),
+        expect: None,
     }
 }
 
 /// Convert `AgentSync` rule to Windsurf format
 #[must_use]
 pub fn agentsync_to_windsurf(agentsync_rule: &AgentSyncRule) -> WindsurfRule {
+    let mut windsurf = windsurf_rule_for(agentsync_rule);
+    windsurf.globs = apply_exclusions(
+        &windsurf.globs,
+        &agentsync_rule.exclude_globs,
+        Tool::Windsurf,
+    )
+    .globs;
+    windsurf
+}
+
+/// Windsurf rule for `agentsync_rule`, before `excludeGlobs` are applied
+fn windsurf_rule_for(agentsync_rule: &AgentSyncRule) -> WindsurfRule {
     let windsurf_config = agentsync_rule.windsurf.as_ref();
+
+    if let Some(activation) = agentsync_rule.activation {
+        let trigger = match activation {
+            Activation::Always => {
+                return WindsurfRule {
+                    trigger: WindsurfTrigger::AlwaysOn,
+                    description: String::new(),
+                    globs: String::new(),
+                };
+            }
+            Activation::Glob | Activation::GlobOrModel => WindsurfTrigger::Glob,
+            Activation::ModelDecision => WindsurfTrigger::ModelDecision,
+            Activation::Manual => WindsurfTrigger::Manual,
+        };
+        // Windsurf keeps globs on any trigger, so the section's globs survive as-is
+        let globs = match windsurf_config {
+            Some(config) => normalize_globs(&config.globs),
+            None if activation.uses_globs() => {
+                activation_globs(agentsync_rule, Tool::Windsurf, None)
+            }
+            None => String::new(),
+        };
+        return WindsurfRule {
+            trigger,
+            description: agentsync_rule.description.clone(),
+            globs,
+        };
+    }
+
     let trigger = windsurf_config.map_or_else(Default::default, |c| c.trigger.clone());
 
     // Always apply should not have description or globs
@@ -45,7 +111,7 @@ pub fn agentsync_to_windsurf(agentsync_rule: &AgentSyncRule) -> WindsurfRule {
                 if is_universal_glob(&agentsync_rule.globs) {
                     String::new()
                 } else {
-                    normalize_globs(&agentsync_rule.globs)
+                    top_level_globs(agentsync_rule, Tool::Windsurf)
                 }
             },
             |c| normalize_globs(&c.globs),
@@ -78,6 +144,60 @@ pub fn agentsync_rule_to_windsurf(rule: &Rule<AgentSyncRule>) -> Rule<WindsurfRu
     }
 }
 
+/// Fields of an `AgentSync` rule that Windsurf drops or fills with defaults
+#[must_use]
+pub fn windsurf_notes(agentsync_rule: &AgentSyncRule) -> Vec<ConversionNote> {
+    let mut notes = Vec::new();
+
+    if agentsync_rule.activation == Some(Activation::Always) {
+        if !agentsync_rule.description.is_empty() {
+            notes.push(ConversionNote::dropped(
+                "description",
+                "`description` dropped: Windsurf omits it for always_on rules",
+            ));
+        }
+        return notes;
+    }
+    if agentsync_rule.activation.is_some() {
+        return notes;
+    }
+
+    match agentsync_rule.windsurf.as_ref() {
+        None => {
+            notes.push(ConversionNote::defaulted(
+                "windsurf.trigger",
+                format!(
+                    "`windsurf.trigger` defaulted to {} (no `windsurf` section)",
+                    WindsurfTrigger::default()
+                ),
+            ));
+            if !is_universal_glob(&agentsync_rule.globs) {
+                notes.push(ConversionNote::defaulted(
+                    "windsurf.globs",
+                    "`windsurf.globs` defaulted to the top-level `globs`",
+                ));
+            }
+        }
+        Some(config) if config.trigger == WindsurfTrigger::AlwaysOn => {
+            if !agentsync_rule.description.is_empty() {
+                notes.push(ConversionNote::dropped(
+                    "description",
+                    "`description` dropped: Windsurf omits it for always_on rules",
+                ));
+            }
+            if !config.globs.is_empty() {
+                notes.push(ConversionNote::dropped(
+                    "windsurf.globs",
+                    "`windsurf.globs` dropped: Windsurf omits globs for always_on rules",
+                ));
+            }
+        }
+        Some(_) => {}
+    }
+
+    notes
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
@@ -154,13 +274,17 @@ mod tests {
         let agentsync = AgentSyncRule {
             targets: vec!["*".to_string()],
             description: "Test rule".to_string(),
+            tags: Vec::new(),
+            activation: None,
             globs: "**/*.rs".to_string(),
+            exclude_globs: String::new(),
             cursor: None,
             windsurf: Some(WindsurfConfig {
                 trigger: WindsurfTrigger::Glob,
                 globs: "**/*.rs".to_string(),
             }),
             copilot: None,
+            expect: None,
         };
 
         let windsurf = agentsync_to_windsurf(&agentsync);
@@ -175,10 +299,14 @@ mod tests {
         let agentsync = AgentSyncRule {
             targets: vec!["*".to_string()],
             description: "Test rule".to_string(),
+            tags: Vec::new(),
+            activation: None,
             globs: "**/*.rs".to_string(),
+            exclude_globs: String::new(),
             cursor: None,
             windsurf: None,
             copilot: None,
+            expect: None,
         };
 
         let windsurf = agentsync_to_windsurf(&agentsync);
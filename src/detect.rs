@@ -0,0 +1,362 @@
+//! Project type detection for `agentsync init`.
+//!
+//! Looks for well-known manifest files in the project root and proposes starter
+//! rules for the languages and frameworks they reveal. Nothing is written here;
+//! `init` asks before creating each proposed rule.
+
+use crate::AgentSyncError;
+use fs_err as fs;
+use indoc::indoc;
+use std::path::Path;
+use tracing::warn;
+
+/// A starter rule `init` offers to create
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proposal {
+    /// Name of the rule to create
+    pub name: &'static str,
+    /// Why it was proposed, e.g. "Cargo.toml"
+    pub reason: String,
+    /// Built-in template to start from
+    pub template: Option<&'static str>,
+    /// Overrides the template's description
+    pub description: Option<&'static str>,
+    /// Overrides the template's globs
+    pub globs: Option<&'static str>,
+    /// Rule body when there is no template
+    pub body: Option<&'static str>,
+}
+
+impl Proposal {
+    fn from_template(template: &'static str, reason: &str) -> Self {
+        Self {
+            name: template,
+            reason: reason.to_string(),
+            template: Some(template),
+            description: None,
+            globs: None,
+            body: None,
+        }
+    }
+
+    fn custom(
+        name: &'static str,
+        reason: &str,
+        description: &'static str,
+        globs: &'static str,
+        body: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            reason: reason.to_string(),
+            template: None,
+            description: Some(description),
+            globs: Some(globs),
+            body: Some(body),
+        }
+    }
+}
+
+const PYTHON_MARKERS: [&str; 4] = ["pyproject.toml", "requirements.txt", "setup.py", "Pipfile"];
+
+/// Starter rules for the project at `project_root`, in a stable order and without duplicates
+pub fn detect_proposals(project_root: &Path) -> Vec<Proposal> {
+    let has = |file: &str| project_root.join(file).is_file();
+    let mut proposals = Vec::new();
+
+    if has("Cargo.toml") {
+        proposals.push(Proposal::from_template("rust", "Cargo.toml"));
+    }
+
+    if let Some(marker) = PYTHON_MARKERS.iter().find(|marker| has(marker)) {
+        proposals.push(Proposal::from_template("python", marker));
+
+        let manifest = python_manifests(project_root, has);
+        if manifest.contains("django") {
+            proposals.push(Proposal::custom(
+                "django",
+                marker,
+                "Django conventions",
+                "**/*.py,**/templates/**/*.html",
+                DJANGO_BODY,
+            ));
+        }
+        if manifest.contains("fastapi") {
+            proposals.push(Proposal::custom(
+                "fastapi",
+                marker,
+                "FastAPI conventions",
+                "**/*.py",
+                FASTAPI_BODY,
+            ));
+        }
+    }
+
+    if has("package.json") {
+        let dependencies = package_dependencies(&project_root.join("package.json"));
+        let depends_on = |name: &str| dependencies.iter().any(|dep| dep == name);
+
+        if has("tsconfig.json") || depends_on("typescript") {
+            let reason = if has("tsconfig.json") {
+                "tsconfig.json"
+            } else {
+                "package.json"
+            };
+            proposals.push(Proposal::from_template("typescript", reason));
+        } else {
+            proposals.push(Proposal::custom(
+                "javascript",
+                "package.json",
+                "JavaScript coding conventions",
+                "**/*.js,**/*.jsx,**/*.mjs,**/*.cjs",
+                JAVASCRIPT_BODY,
+            ));
+        }
+
+        if depends_on("react") {
+            proposals.push(Proposal::custom(
+                "react",
+                "package.json",
+                "React component conventions",
+                "**/*.jsx,**/*.tsx",
+                REACT_BODY,
+            ));
+        }
+        if depends_on("vue") {
+            proposals.push(Proposal::custom(
+                "vue",
+                "package.json",
+                "Vue component conventions",
+                "**/*.vue",
+                VUE_BODY,
+            ));
+        }
+    }
+
+    if has("go.mod") {
+        proposals.push(Proposal::custom(
+            "go",
+            "go.mod",
+            "Go coding conventions",
+            "**/*.go",
+            GO_BODY,
+        ));
+    }
+
+    if has("Gemfile") {
+        proposals.push(Proposal::custom(
+            "ruby",
+            "Gemfile",
+            "Ruby coding conventions",
+            "**/*.rb",
+            RUBY_BODY,
+        ));
+    }
+
+    if let Some(marker) = ["pom.xml", "build.gradle", "build.gradle.kts"]
+        .iter()
+        .find(|marker| has(marker))
+    {
+        proposals.push(Proposal::custom(
+            "java",
+            marker,
+            "Java coding conventions",
+            "**/*.java",
+            JAVA_BODY,
+        ));
+    }
+
+    proposals
+}
+
+/// Every Python manifest in the project, lowercased and joined.
+///
+/// Like a broken `package.json`, an unreadable manifest only costs the framework
+/// proposals, so it is logged and left out.
+fn python_manifests(project_root: &Path, has: impl Fn(&str) -> bool) -> String {
+    let mut manifests = Vec::new();
+    for marker in PYTHON_MARKERS.iter().filter(|marker| has(marker)) {
+        let path = project_root.join(marker);
+        match fs::read_to_string(&path) {
+            Ok(content) => manifests.push(content.to_lowercase()),
+            Err(e) => warn!("Ignoring {}: {e}", path.display()),
+        }
+    }
+    manifests.join("\n")
+}
+
+/// Names of every dependency in a `package.json`, dev dependencies included.
+///
+/// An unreadable manifest only costs the framework proposals, so it is logged and ignored.
+fn package_dependencies(path: &Path) -> Vec<String> {
+    let manifest = fs::read_to_string(path)
+        .map_err(AgentSyncError::from)
+        .and_then(|content| Ok(serde_json::from_str::<serde_json::Value>(&content)?));
+    let manifest = match manifest {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            warn!("Ignoring {}: {e}", path.display());
+            return Vec::new();
+        }
+    };
+
+    ["dependencies", "devDependencies", "peerDependencies"]
+        .iter()
+        .filter_map(|section| manifest.get(section)?.as_object())
+        .flat_map(|deps| deps.keys().cloned())
+        .collect()
+}
+
+const DJANGO_BODY: &str = indoc! {"
+    # Django
+
+    - Keep business logic in models, managers or service functions, not in views.
+    - Use the ORM and query parameters; never format SQL strings by hand.
+    - Add a migration for every model change and keep migrations in version control.
+    - Use `select_related` and `prefetch_related` to avoid N+1 queries.
+    - Read settings from the environment; never commit secrets to `settings.py`.
+"};
+
+const FASTAPI_BODY: &str = indoc! {"
+    # FastAPI
+
+    - Declare request and response bodies as Pydantic models.
+    - Use dependencies (`Depends`) for database sessions, auth and shared setup.
+    - Make I/O-bound endpoints `async` and avoid blocking calls inside them.
+    - Group endpoints with `APIRouter`, one router per resource.
+    - Return explicit status codes and raise `HTTPException` for client errors.
+"};
+
+const JAVASCRIPT_BODY: &str = indoc! {"
+    # JavaScript
+
+    - Use ES modules (`import`/`export`) and `const`/`let`, never `var`.
+    - Use strict equality (`===`) and handle `null`/`undefined` explicitly.
+    - Prefer `async`/`await` over promise chains and always handle rejections.
+    - Keep the code formatted and linted with the project's tools (e.g. Prettier, ESLint).
+    - Document non-obvious functions with JSDoc, including parameter types.
+"};
+
+const REACT_BODY: &str = indoc! {"
+    # React
+
+    - Write function components and hooks; no class components.
+    - Follow the rules of hooks and keep effect dependency lists complete.
+    - Keep components small; lift shared state up or into context only when needed.
+    - Give list items stable `key`s that are not array indexes.
+    - Test components through what the user sees (e.g. Testing Library), not internals.
+"};
+
+const VUE_BODY: &str = indoc! {"
+    # Vue
+
+    - Use single-file components with `<script setup>` and the Composition API.
+    - Declare props and emits explicitly, with types.
+    - Keep templates simple; move logic into computed properties or composables.
+    - Never mutate props; emit events instead.
+    - Give `v-for` items stable `:key`s.
+"};
+
+const GO_BODY: &str = indoc! {"
+    # Go
+
+    - Format with `gofmt` and keep `go vet` clean.
+    - Return errors instead of panicking, and wrap them with context (`fmt.Errorf(\"...: %w\", err)`).
+    - Accept interfaces, return concrete types; keep interfaces small.
+    - Pass `context.Context` as the first argument to functions that do I/O.
+    - Write table-driven tests with the standard `testing` package.
+"};
+
+const RUBY_BODY: &str = indoc! {"
+    # Ruby
+
+    - Follow the project's RuboCop configuration.
+    - Prefer small methods and objects with a single responsibility.
+    - Use keyword arguments for methods with more than two parameters.
+    - Raise specific error classes and never rescue `Exception`.
+    - Cover new behavior with specs or tests.
+"};
+
+const JAVA_BODY: &str = indoc! {"
+    # Java
+
+    - Follow the project's formatter and static analysis settings.
+    - Prefer immutable objects: `final` fields, records and unmodifiable collections.
+    - Use `Optional` for absent return values instead of `null`.
+    - Use try-with-resources for anything `AutoCloseable`.
+    - Write unit tests with JUnit 5 for new behavior.
+"};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project_with(files: &[(&str, &str)]) -> TempDir {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        for (name, content) in files {
+            fs::write(temp_dir.path().join(name), content).expect("test operation should succeed");
+        }
+        temp_dir
+    }
+
+    fn names(proposals: &[Proposal]) -> Vec<&str> {
+        proposals.iter().map(|proposal| proposal.name).collect()
+    }
+
+    #[test]
+    fn test_detect_cargo_project() {
+        let temp_dir = project_with(&[("Cargo.toml", "[package]\nname = \"x\"\n")]);
+
+        let proposals = detect_proposals(temp_dir.path());
+        assert_eq!(names(&proposals), ["rust"]);
+        assert_eq!(proposals[0].template, Some("rust"));
+        assert_eq!(proposals[0].reason, "Cargo.toml");
+    }
+
+    #[test]
+    fn test_detect_frameworks() {
+        let temp_dir = project_with(&[
+            (
+                "package.json",
+                r#"{"dependencies": {"react": "^18"}, "devDependencies": {"typescript": "^5"}}"#,
+            ),
+            ("requirements.txt", "Django==5.0\n"),
+            ("go.mod", "module example.com/x\n"),
+        ]);
+
+        let proposals = detect_proposals(temp_dir.path());
+        assert_eq!(
+            names(&proposals),
+            ["python", "django", "typescript", "react", "go"]
+        );
+        assert_eq!(proposals[3].globs, Some("**/*.jsx,**/*.tsx"));
+    }
+
+    #[test]
+    fn test_detect_plain_javascript_and_empty_project() {
+        let temp_dir = project_with(&[("package.json", "{}")]);
+        let proposals = detect_proposals(temp_dir.path());
+        assert_eq!(names(&proposals), ["javascript"]);
+
+        let broken = project_with(&[("package.json", "{not json")]);
+        let proposals = detect_proposals(broken.path());
+        assert_eq!(names(&proposals), ["javascript"]);
+
+        let empty = TempDir::new().expect("should create temp dir");
+        assert!(detect_proposals(empty.path()).is_empty());
+    }
+
+    #[test]
+    fn test_detect_skips_unreadable_python_manifest() {
+        let temp_dir = project_with(&[(
+            "pyproject.toml",
+            "[project]\ndependencies = [\"fastapi\"]\n",
+        )]);
+        fs::write(temp_dir.path().join("requirements.txt"), b"django\xff\n")
+            .expect("should write manifest");
+
+        let proposals = detect_proposals(temp_dir.path());
+        assert_eq!(names(&proposals), ["python", "fastapi"]);
+    }
+}
@@ -28,15 +28,27 @@ pub enum AgentSyncError {
     InvalidTool { tool: String },
 
     /// Custom Display for formatted frontmatter parse error
-    #[error("{}", format_frontmatter_parse_error(file, line.as_ref(), message))]
+    #[error(
+        "{}",
+        format_frontmatter_parse_error(
+            file,
+            line.as_ref(),
+            column.as_ref(),
+            source_line.as_deref(),
+            message
+        )
+    )]
     FrontmatterParse {
         file: String,
         line: Option<usize>,
+        column: Option<usize>,
+        /// Offending frontmatter line, rendered as a snippet under the message
+        source_line: Option<String>,
         message: String,
     },
 
     #[error(
-        "Project not initialized\n\n{hint}{colon} Run {cmd} to initialize a new project\n{hint}{colon} This will create {config} and {dir}",
+        "Project not initialized (no {config} found in this directory or its parents)\n\n{hint}{colon} Run {cmd} to initialize a new project\n{hint}{colon} This will create {config} and {dir}",
         hint = "hint".cyan().bold(),
         colon = ":".bold(),
         cmd = "`agentsync init`".green(),
@@ -81,6 +93,53 @@ pub enum AgentSyncError {
         source: Box<AgentSyncError>,
     },
 
+    #[error(
+        "Validation failed with {errors} error(s) and {warnings} warning(s)\n\n{hint}{colon} Fix the problems listed above and run {cmd} again{strict_hint}",
+        hint = "hint".cyan().bold(),
+        colon = ":".bold(),
+        cmd = "`agentsync validate`".green(),
+        strict_hint = if *errors == 0 {
+            format!("\n{}{} Warnings fail validation because {} is set", "hint".cyan().bold(), ":".bold(), "`--strict`".green())
+        } else {
+            String::new()
+        }
+    )]
+    ValidationFailed { errors: usize, warnings: usize },
+
+    #[error(
+        "{changed} round trip(s) changed a rule\n\n{hint}{colon} Run {cmd} to see what each tool drops or defaults",
+        hint = "hint".cyan().bold(),
+        colon = ":".bold(),
+        cmd = "`agentsync show <rule> --annotate`".green()
+    )]
+    RoundtripUnstable { changed: usize },
+
+    #[error(
+        "{failed} rule expectation(s) failed\n\n{hint}{colon} Run {cmd} to see how each tool treats a path",
+        hint = "hint".cyan().bold(),
+        colon = ":".bold(),
+        cmd = "`agentsync explain <path>`".green()
+    )]
+    ExpectationsFailed { failed: usize },
+
+    #[error(
+        "Rule not found: {name}\n\n{hint}{colon} Run {cmd} to see available rules",
+        name = name.red().bold(),
+        hint = "hint".cyan().bold(),
+        colon = ":".bold(),
+        cmd = "`agentsync list`".green()
+    )]
+    RuleNotFound { name: String },
+
+    #[error(
+        "Template not found: {name}\n\n{hint}{colon} Run {cmd} to see available templates",
+        name = name.red().bold(),
+        hint = "hint".cyan().bold(),
+        colon = ":".bold(),
+        cmd = "`agentsync templates`".green()
+    )]
+    TemplateNotFound { name: String },
+
     #[error(
         "Path traversal detected: '{target}' escapes base directory '{base}'\n\nPaths must stay within the base directory."
     )]
@@ -156,13 +215,23 @@ fn format_permission_denied(path: &str) -> String {
     msg
 }
 
-fn format_frontmatter_parse_error(file: &str, line: Option<&usize>, message: &str) -> String {
+fn format_frontmatter_parse_error(
+    file: &str,
+    line: Option<&usize>,
+    column: Option<&usize>,
+    source_line: Option<&str>,
+    message: &str,
+) -> String {
     let mut msg = format!("Invalid frontmatter in {}", file.cyan());
 
     #[allow(clippy::format_push_string)]
     {
         if let Some(line_num) = line {
-            msg.push_str(&format!(" at {}", format!("line {line_num}").yellow()));
+            let location = match column {
+                Some(col) => format!("line {line_num}, column {col}"),
+                None => format!("line {line_num}"),
+            };
+            msg.push_str(&format!(" at {}", location.yellow()));
         }
 
         msg.push_str(&format!(
@@ -171,6 +240,15 @@ fn format_frontmatter_parse_error(file: &str, line: Option<&usize>, message: &st
             message.replace('\n', "\n  ")
         ));
 
+        if let (Some(line_num), Some(source)) = (line, source_line) {
+            msg.push_str(&format_source_snippet(
+                file,
+                *line_num,
+                column.copied(),
+                source,
+            ));
+        }
+
         msg.push_str(&format!(
             "\n\n{}{} Frontmatter must be valid key-value pairs enclosed in {} markers",
             "hint".cyan().bold(),
@@ -193,6 +271,37 @@ fn format_frontmatter_parse_error(file: &str, line: Option<&usize>, message: &st
     msg
 }
 
+/// Render a rustc-style snippet: location arrow, source line and caret
+fn format_source_snippet(file: &str, line: usize, column: Option<usize>, source: &str) -> String {
+    let line_label = line.to_string();
+    let gutter = " ".repeat(line_label.len());
+    let pipe = "|".blue().bold().to_string();
+
+    let location = match column {
+        Some(col) => format!("{file}:{line}:{col}"),
+        None => format!("{file}:{line}"),
+    };
+
+    let mut snippet = format!(
+        "\n\n{gutter}{} {location}\n{gutter} {pipe}\n{} {pipe} {source}",
+        "-->".blue().bold(),
+        line_label.blue().bold()
+    );
+
+    #[allow(clippy::format_push_string)]
+    if let Some(col) = column {
+        // Keep tabs so the caret lines up with the source line
+        let padding: String = source
+            .chars()
+            .take(col.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        snippet.push_str(&format!("\n{gutter} {pipe} {padding}{}", "^".red().bold()));
+    }
+
+    snippet
+}
+
 fn format_conversion_failed(
     rule: &str,
     from_tool: &str,
@@ -0,0 +1,295 @@
+//! Check the paths rules declare in `expect` (`agentsync test`).
+//!
+//! A rule can list files it must apply to (`matches`) and files it must not
+//! (`excludes`). Each path is checked against what every targeted tool receives
+//! after conversion, with globs matched in that tool's dialect, so a pattern
+//! that only works in one tool fails in the others.
+
+use crate::converter::{Activation, converted_activation};
+use crate::explain::{Reason, rule_reason};
+use crate::fs::{Tool, discover_rules, extract_rule_name, read_rule_file};
+use crate::models::AgentSyncRule;
+use crate::parser::parse_frontmatter;
+use crate::{AgentSyncError, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::path::Path;
+
+/// Which list of the `expect` section a path comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Expectation {
+    /// The rule must apply to the path
+    Matches,
+    /// The rule must not apply to the path
+    Excludes,
+}
+
+/// Result of one expectation in one tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Pass,
+    Fail,
+    /// The tool doesn't attach the rule by globs (model decision or manual)
+    Skipped,
+}
+
+/// One expected path checked in one tool
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpectationCheck {
+    pub rule: String,
+    pub tool: String,
+    pub path: String,
+    pub expected: Expectation,
+    pub outcome: Outcome,
+    /// How the tool treats the rule for the path
+    pub reason: Reason,
+    /// Patterns the tool can't use, from the glob dialect
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub glob_issues: Vec<String>,
+}
+
+/// Every expectation of every rule, checked in every enabled tool
+#[derive(Debug, Clone, Serialize)]
+pub struct TestReport {
+    pub tools: Vec<String>,
+    pub checks: Vec<ExpectationCheck>,
+    /// Source rules that could not be read or parsed, with the error
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<(String, String)>,
+}
+
+impl TestReport {
+    /// Number of checks with the given outcome
+    #[must_use]
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.outcome == outcome)
+            .count()
+    }
+
+    /// Print each rule's checks, grouped by rule
+    #[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
+    pub fn print(&self) {
+        for (rule, error) in &self.errors {
+            println!("{} {rule}: {}", "✗".red(), format!("error: {error}").red());
+        }
+
+        if self.checks.is_empty() {
+            println!(
+                "No rules declare expectations. Add {} to a rule's frontmatter.",
+                "`expect: { matches: [...], excludes: [...] }`".green()
+            );
+            return;
+        }
+
+        let width = self
+            .checks
+            .iter()
+            .map(|check| check.tool.len())
+            .max()
+            .unwrap_or_default();
+        let mut current_rule = None;
+        for check in &self.checks {
+            if current_rule != Some(&check.rule) {
+                println!("{}", check.rule.bold());
+                current_rule = Some(&check.rule);
+            }
+
+            let verb = match check.expected {
+                Expectation::Matches => "matches",
+                Expectation::Excludes => "excludes",
+            };
+            let line = format!("{:<width$}  {verb} {}", check.tool, check.path);
+            match check.outcome {
+                Outcome::Pass => println!("  {} {line}", "✓".green()),
+                Outcome::Fail => println!("  {} {line}: {}", "✗".red(), check.reason.red()),
+                Outcome::Skipped => println!(
+                    "  {} {}",
+                    "·".dimmed(),
+                    format!("{line}: skipped, {}", check.reason).dimmed()
+                ),
+            }
+            if check.outcome == Outcome::Fail {
+                for issue in &check.glob_issues {
+                    println!("      {} {issue}", "⚠".yellow());
+                }
+            }
+        }
+
+        println!(
+            "\n{} passed, {} failed, {} skipped",
+            self.count(Outcome::Pass),
+            self.count(Outcome::Fail),
+            self.count(Outcome::Skipped)
+        );
+    }
+}
+
+/// Check the `expect` paths of every source rule (or only `only`) in each of `tools`
+pub fn test_expectations(
+    project_root: &Path,
+    tools: &[Tool],
+    only: Option<&str>,
+) -> Result<TestReport> {
+    let mut report = TestReport {
+        tools: tools.iter().map(|tool| tool.name().to_string()).collect(),
+        checks: Vec::new(),
+        errors: Vec::new(),
+    };
+    let mut found = false;
+
+    for source_path in discover_rules(project_root, Tool::AgentSync)? {
+        let Some(name) = extract_rule_name(&source_path) else {
+            continue;
+        };
+        if only.is_some_and(|only| only != name) {
+            continue;
+        }
+        found = true;
+
+        let path_str = source_path.display().to_string();
+        let parsed = read_rule_file(&source_path)
+            .and_then(|content| parse_frontmatter::<AgentSyncRule>(&content, Some(&path_str)));
+        let rule = match parsed {
+            Ok(rule) => rule.frontmatter,
+            Err(e) => {
+                report.errors.push((name, e.to_string()));
+                continue;
+            }
+        };
+
+        for &tool in tools {
+            if tool == Tool::AgentSync || !rule.targets.iter().any(|t| t == "*" || t == tool.name())
+            {
+                continue;
+            }
+            report.checks.extend(check_rule(&name, &rule, tool));
+        }
+    }
+
+    if let Some(name) = only.filter(|_| !found) {
+        return Err(AgentSyncError::RuleNotFound {
+            name: name.to_string(),
+        });
+    }
+
+    Ok(report)
+}
+
+/// Check every expected path of `rule` against what `tool` receives
+fn check_rule(name: &str, rule: &AgentSyncRule, tool: Tool) -> Vec<ExpectationCheck> {
+    let Some(expect) = &rule.expect else {
+        return Vec::new();
+    };
+    let attached_by_globs = converted_activation(rule, tool)
+        .is_some_and(|(activation, _)| activation == Activation::Always || activation.uses_globs());
+
+    let expected_paths = expect
+        .matches
+        .iter()
+        .map(|path| (Expectation::Matches, path))
+        .chain(
+            expect
+                .excludes
+                .iter()
+                .map(|path| (Expectation::Excludes, path)),
+        );
+
+    expected_paths
+        .map(|(expected, path)| {
+            let path = path.trim_start_matches("./");
+            let verdict = rule_reason(name, rule, tool, path);
+            let outcome = if !attached_by_globs {
+                Outcome::Skipped
+            } else if verdict.reason.applies() == (expected == Expectation::Matches) {
+                Outcome::Pass
+            } else {
+                Outcome::Fail
+            };
+
+            ExpectationCheck {
+                rule: name.to_string(),
+                tool: tool.name().to_string(),
+                path: path.to_string(),
+                expected,
+                outcome,
+                reason: verdict.reason,
+                glob_issues: verdict.glob_issues,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::project_with_rules;
+
+    fn outcomes(report: &TestReport) -> Vec<(&str, &str, Outcome)> {
+        report
+            .checks
+            .iter()
+            .map(|check| (check.tool.as_str(), check.path.as_str(), check.outcome))
+            .collect()
+    }
+
+    #[test]
+    fn test_expectations_per_tool_dialect() {
+        let temp_dir = project_with_rules(&[(
+            "python",
+            "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"*.py\"\ncopilot:\n  applyTo: \"*.py\"\nexpect: { matches: [\"src/a.py\"], excludes: [\"./tests/x.txt\"] }\n---\n",
+        )]);
+
+        let report = test_expectations(temp_dir.path(), &[Tool::Cursor, Tool::Copilot], None)
+            .expect("should test");
+
+        // Copilot reads its own `*.py` as root-level files only
+        assert_eq!(
+            outcomes(&report),
+            [
+                ("cursor", "src/a.py", Outcome::Pass),
+                ("cursor", "tests/x.txt", Outcome::Pass),
+                ("copilot", "src/a.py", Outcome::Fail),
+                ("copilot", "tests/x.txt", Outcome::Pass),
+            ]
+        );
+        assert_eq!(report.count(Outcome::Fail), 1);
+    }
+
+    #[test]
+    fn test_expectations_always_on_and_manual() {
+        let temp_dir = project_with_rules(&[
+            (
+                "core",
+                "---\ntargets: [\"*\"]\nactivation: always\nexpect:\n  excludes:\n    - docs/a.md\n---\n",
+            ),
+            (
+                "commits",
+                "---\ntargets: [\"*\"]\nactivation: manual\nexpect: { matches: [\"README.md\"] }\n---\n",
+            ),
+            ("plain", "---\ntargets: [\"*\"]\n---\n"),
+        ]);
+
+        let report =
+            test_expectations(temp_dir.path(), &[Tool::Cursor], None).expect("should test");
+        assert_eq!(
+            outcomes(&report),
+            [
+                ("cursor", "README.md", Outcome::Skipped),
+                ("cursor", "docs/a.md", Outcome::Fail),
+            ]
+        );
+        assert_eq!(report.checks[1].reason, Reason::AlwaysOn);
+
+        let only = test_expectations(temp_dir.path(), &[Tool::Cursor], Some("commits"))
+            .expect("should test");
+        assert_eq!(only.checks.len(), 1);
+        assert!(matches!(
+            test_expectations(temp_dir.path(), &[Tool::Cursor], Some("missing")),
+            Err(AgentSyncError::RuleNotFound { .. })
+        ));
+    }
+}
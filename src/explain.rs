@@ -0,0 +1,398 @@
+//! Which rules each tool applies to a file, and why (`agentsync explain`).
+//!
+//! Rules are converted for each tool first, so the answer reflects what the
+//! tool actually receives, and globs are matched in that tool's dialect.
+
+use crate::converter::{Activation, converted_activation};
+use crate::fs::{Tool, discover_rules, extract_rule_name, read_rule_file};
+use crate::globs::{Dialect, GlobMatcher};
+use crate::models::AgentSyncRule;
+use crate::parser::parse_frontmatter;
+use crate::{AgentSyncError, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::path::{Component, Path, PathBuf};
+
+/// Why a rule is, or is not, in context for the file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Reason {
+    /// Loaded for every file
+    AlwaysOn,
+    /// Attached because one of its globs matches
+    Glob { pattern: String },
+    /// Not attached, but the agent may pick it from its description
+    Description { description: String },
+    /// Only loaded when @-mentioned
+    Manual,
+    /// Its globs don't match the file
+    NotMatched { globs: String },
+}
+
+impl Reason {
+    /// Whether the tool loads the rule for the file without being asked
+    #[must_use]
+    pub const fn applies(&self) -> bool {
+        matches!(self, Self::AlwaysOn | Self::Glob { .. })
+    }
+
+    /// Display order: applied rules first, unmatched ones last
+    const fn rank(&self) -> u8 {
+        match self {
+            Self::AlwaysOn | Self::Glob { .. } => 0,
+            Self::Description { .. } => 1,
+            Self::Manual => 2,
+            Self::NotMatched { .. } => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlwaysOn => write!(f, "always on"),
+            Self::Glob { pattern } => write!(f, "matched by glob `{pattern}`"),
+            Self::Description { description } if description.is_empty() => {
+                write!(f, "available via description (none set)")
+            }
+            Self::Description { description } => {
+                write!(f, "available via description: {description:?}")
+            }
+            Self::Manual => write!(f, "manual only (@-mention to load)"),
+            Self::NotMatched { globs } => write!(f, "not matched by `{globs}`"),
+        }
+    }
+}
+
+/// One rule's verdict for one tool
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleReason {
+    pub rule: String,
+    pub reason: Reason,
+    /// Patterns the tool can't use, from the glob dialect
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub glob_issues: Vec<String>,
+}
+
+/// Every targeted rule's verdict for one tool
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolExplanation {
+    pub tool: String,
+    pub rules: Vec<RuleReason>,
+}
+
+/// What `agentsync explain` reports for a path
+#[derive(Debug, Clone, Serialize)]
+pub struct Explanation {
+    /// Path relative to the project root
+    pub path: String,
+    pub tools: Vec<ToolExplanation>,
+    /// Source rules that could not be read or parsed, with the error
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<(String, String)>,
+}
+
+impl Explanation {
+    /// Print each tool's rules, applied ones first
+    #[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
+    pub fn print(&self) {
+        println!("{}", self.path.bold());
+
+        for (rule, error) in &self.errors {
+            println!("{} {rule}: {}", "✗".red(), format!("error: {error}").red());
+        }
+
+        for tool in &self.tools {
+            println!("\n{}", tool.tool.bold());
+            if tool.rules.is_empty() {
+                println!("  {}", "(no rules target this tool)".dimmed());
+            }
+
+            let width = tool
+                .rules
+                .iter()
+                .map(|rule| rule.rule.len())
+                .max()
+                .unwrap_or_default();
+            for rule in &tool.rules {
+                let line = format!("{:<width$}  {}", rule.rule, rule.reason);
+                match rule.reason {
+                    Reason::AlwaysOn | Reason::Glob { .. } => {
+                        println!("  {} {line}", "✓".green());
+                    }
+                    Reason::Description { .. } => println!("  {} {line}", "?".yellow()),
+                    Reason::Manual => println!("  {} {line}", "@".cyan()),
+                    Reason::NotMatched { .. } => {
+                        println!("  {} {}", "·".dimmed(), line.dimmed());
+                    }
+                }
+                for issue in &rule.glob_issues {
+                    println!("      {} {issue}", "⚠".yellow());
+                }
+            }
+        }
+    }
+}
+
+/// `path` relative to `project_root`, with `/` separators.
+///
+/// Relative paths are taken from `cwd`. The file doesn't have to exist.
+pub fn project_relative_path(project_root: &Path, cwd: &Path, path: &Path) -> Result<String> {
+    let absolute = normalize(&cwd.join(path));
+    let relative = absolute
+        .strip_prefix(normalize(project_root))
+        .ok()
+        .or_else(|| {
+            let canonical_root = project_root.canonicalize().ok()?;
+            absolute.strip_prefix(canonical_root).ok()
+        })
+        .ok_or_else(|| AgentSyncError::PathTraversal {
+            base: project_root.display().to_string(),
+            target: path.display().to_string(),
+        })?;
+
+    Ok(relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+/// Resolve `.` and `..` without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Explain which rules each of `tools` applies to `path` (relative to the project root)
+pub fn explain_path(project_root: &Path, path: &str, tools: &[Tool]) -> Result<Explanation> {
+    let mut explanation = Explanation {
+        path: path.to_string(),
+        tools: tools
+            .iter()
+            .filter(|tool| **tool != Tool::AgentSync)
+            .map(|tool| ToolExplanation {
+                tool: tool.name().to_string(),
+                rules: Vec::new(),
+            })
+            .collect(),
+        errors: Vec::new(),
+    };
+
+    for source_path in discover_rules(project_root, Tool::AgentSync)? {
+        let Some(name) = extract_rule_name(&source_path) else {
+            continue;
+        };
+
+        let path_str = source_path.display().to_string();
+        let parsed = read_rule_file(&source_path)
+            .and_then(|content| parse_frontmatter::<AgentSyncRule>(&content, Some(&path_str)));
+        let rule = match parsed {
+            Ok(rule) => rule.frontmatter,
+            Err(e) => {
+                explanation.errors.push((name, e.to_string()));
+                continue;
+            }
+        };
+
+        for entry in &mut explanation.tools {
+            let tool: Tool = entry.tool.parse()?;
+            if !rule.targets.iter().any(|t| t == "*" || t == tool.name()) {
+                continue;
+            }
+            entry.rules.push(rule_reason(&name, &rule, tool, path));
+        }
+    }
+
+    for entry in &mut explanation.tools {
+        entry.rules.sort_by_key(|rule| rule.reason.rank());
+    }
+
+    Ok(explanation)
+}
+
+/// How `tool` treats rule `name` for `path`, after converting the rule for it
+pub(crate) fn rule_reason(name: &str, rule: &AgentSyncRule, tool: Tool, path: &str) -> RuleReason {
+    let (activation, globs) =
+        converted_activation(rule, tool).unwrap_or((Activation::Manual, String::new()));
+    let matcher = GlobMatcher::new(&globs, Dialect::of(tool));
+
+    let glob_match = matcher.matching_pattern(path).map(|pattern| Reason::Glob {
+        pattern: pattern.to_string(),
+    });
+    let description = || Reason::Description {
+        description: rule.description.clone(),
+    };
+
+    let reason = match activation {
+        Activation::Always => Reason::AlwaysOn,
+        Activation::Glob => glob_match.unwrap_or(Reason::NotMatched { globs }),
+        Activation::GlobOrModel => glob_match.unwrap_or_else(description),
+        Activation::ModelDecision => description(),
+        Activation::Manual => Reason::Manual,
+    };
+
+    RuleReason {
+        rule: name.to_string(),
+        reason,
+        glob_issues: matcher.issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::project_with_rules;
+
+    fn reasons(explanation: &Explanation, tool: &str) -> Vec<(String, Reason)> {
+        explanation
+            .tools
+            .iter()
+            .find(|entry| entry.tool == tool)
+            .expect("tool should be explained")
+            .rules
+            .iter()
+            .map(|rule| (rule.rule.clone(), rule.reason.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_explain_reasons_per_activation() {
+        let temp_dir = project_with_rules(&[
+            ("core", "---\ntargets: [\"*\"]\nactivation: always\n---\n"),
+            (
+                "react",
+                "---\ntargets: [\"*\"]\ndescription: React\nactivation: glob_or_model\nglobs: \"**/*.tsx\"\n---\n",
+            ),
+            (
+                "python",
+                "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"**/*.py\"\n---\n",
+            ),
+            (
+                "review",
+                "---\ntargets: [\"cursor\"]\ndescription: Code review\nactivation: model_decision\n---\n",
+            ),
+            (
+                "commits",
+                "---\ntargets: [\"*\"]\nactivation: manual\n---\n",
+            ),
+        ]);
+
+        let explanation = explain_path(
+            temp_dir.path(),
+            "src/app.tsx",
+            &[Tool::Cursor, Tool::Copilot],
+        )
+        .expect("should explain");
+
+        assert_eq!(
+            reasons(&explanation, "cursor"),
+            [
+                ("core".to_string(), Reason::AlwaysOn),
+                (
+                    "react".to_string(),
+                    Reason::Glob {
+                        pattern: "**/*.tsx".to_string()
+                    }
+                ),
+                (
+                    "review".to_string(),
+                    Reason::Description {
+                        description: "Code review".to_string()
+                    }
+                ),
+                ("commits".to_string(), Reason::Manual),
+                (
+                    "python".to_string(),
+                    Reason::NotMatched {
+                        globs: "**/*.py".to_string()
+                    }
+                ),
+            ]
+        );
+
+        // Copilot has no manual or description-only rules; they load everywhere
+        let copilot = reasons(&explanation, "copilot");
+        assert_eq!(copilot.len(), 4);
+        assert!(copilot[..3].iter().all(|(_, reason)| reason.applies()));
+        assert!(copilot.iter().all(|(name, _)| name != "review"));
+    }
+
+    #[test]
+    fn test_explain_uses_tool_dialect() {
+        let temp_dir = project_with_rules(&[
+            (
+                "ts",
+                "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"*.ts\"\n---\n",
+            ),
+            (
+                "root-ts",
+                "---\ntargets: [\"*\"]\nactivation: glob\ncopilot:\n  applyTo: \"*.ts\"\n---\n",
+            ),
+        ]);
+
+        let explanation = explain_path(
+            temp_dir.path(),
+            "src/index.ts",
+            &[Tool::Cursor, Tool::Copilot],
+        )
+        .expect("should explain");
+
+        assert!(reasons(&explanation, "cursor")[0].1.applies());
+        // Top-level globs are translated; a Copilot section is read as Copilot reads it
+        assert_eq!(
+            reasons(&explanation, "copilot"),
+            [
+                (
+                    "ts".to_string(),
+                    Reason::Glob {
+                        pattern: "**/*.ts".to_string()
+                    }
+                ),
+                (
+                    "root-ts".to_string(),
+                    Reason::NotMatched {
+                        globs: "*.ts".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_project_relative_path() {
+        let root = Path::new("/work/project");
+
+        assert_eq!(
+            project_relative_path(
+                root,
+                Path::new("/work/project/src"),
+                Path::new("../lib/a.rs")
+            )
+            .expect("should resolve"),
+            "lib/a.rs"
+        );
+        assert_eq!(
+            project_relative_path(
+                root,
+                Path::new("/elsewhere"),
+                Path::new("/work/project/./b.py")
+            )
+            .expect("should resolve"),
+            "b.py"
+        );
+        assert!(matches!(
+            project_relative_path(root, Path::new("/work/project"), Path::new("../other/c.py")),
+            Err(AgentSyncError::PathTraversal { .. })
+        ));
+    }
+}
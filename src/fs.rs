@@ -33,6 +33,9 @@ impl FromStr for Tool {
 }
 
 impl Tool {
+    /// Tools that rules are synced to (everything except the AgentSync source)
+    pub const SYNC_TARGETS: [Self; 3] = [Self::Cursor, Self::Copilot, Self::Windsurf];
+
     #[must_use]
     pub const fn name(&self) -> &'static str {
         match self {
@@ -86,18 +89,107 @@ pub fn write_atomic<P: AsRef<Path>>(path: P, content: impl AsRef<[u8]>) -> Resul
     Ok(())
 }
 
-/// Find project root by locating agentsync.json
+/// Name of the configuration file that marks a project root
+pub const CONFIG_FILE: &str = "agentsync.json";
+
+/// Project root and the configuration file it was derived from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectPaths {
+    pub root: PathBuf,
+    pub config: PathBuf,
+}
+
+/// Find project root by locating agentsync.json in the current directory or its ancestors
 pub fn find_project_root() -> Result<PathBuf> {
-    let current_dir = std::env::current_dir()?;
+    find_project_root_from(&std::env::current_dir()?)
+}
+
+/// Find the nearest project root at or above `start`.
+///
+/// Warns when the chosen project is nested inside another one, since running
+/// from a subdirectory then silently ignores the enclosing project.
+pub fn find_project_root_from(start: &Path) -> Result<PathBuf> {
+    let mut projects = discover_project_roots(start).into_iter();
+    let root = projects.next().ok_or(AgentSyncError::NotInitialized)?;
+
+    for outer in projects {
+        tracing::warn!(
+            "Using nested project at {} (enclosing project at {} is ignored)",
+            root.display(),
+            outer.display()
+        );
+    }
+
+    Ok(root)
+}
+
+/// Every directory containing agentsync.json from `start` upwards, nearest first.
+///
+/// The search stops at the filesystem root or after a directory containing
+/// `.git`, so a project never picks up configuration from outside its repository.
+pub fn discover_project_roots(start: &Path) -> Vec<PathBuf> {
+    let mut roots = Vec::new();
 
-    let config_path = current_dir.join("agentsync.json");
-    if config_path.exists() {
-        Ok(current_dir)
+    for dir in start.ancestors() {
+        if dir.join(CONFIG_FILE).is_file() {
+            roots.push(dir.to_path_buf());
+        }
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+
+    roots
+}
+
+/// Resolve an explicit config location (`--config` or `AGENTSYNC_CONFIG`).
+///
+/// A directory resolves to the `agentsync.json` inside it. Relative paths are
+/// resolved against the current directory. The file does not need to exist.
+pub fn resolve_config_path(config: &Path) -> Result<PathBuf> {
+    let path = if config.is_absolute() {
+        config.to_path_buf()
     } else {
-        Err(AgentSyncError::NotInitialized)
+        std::env::current_dir()?.join(config)
+    };
+
+    if path.is_dir() {
+        Ok(path.join(CONFIG_FILE))
+    } else {
+        Ok(path)
     }
 }
 
+/// Locate the project from an explicit config path, or by searching for agentsync.json.
+///
+/// With an explicit config, the project root is the directory containing it,
+/// so commands work from anywhere (e.g. CI scripts).
+pub fn locate_project(config: Option<&Path>) -> Result<ProjectPaths> {
+    let Some(config) = config else {
+        let root = find_project_root()?;
+        return Ok(ProjectPaths {
+            config: root.join(CONFIG_FILE),
+            root,
+        });
+    };
+
+    let config = resolve_config_path(config)?;
+    if !config.is_file() {
+        return Err(AgentSyncError::ConfigNotFound {
+            path: config.display().to_string(),
+        });
+    }
+
+    let root = config
+        .parent()
+        .ok_or_else(|| {
+            AgentSyncError::Other("Config path must have a parent directory".to_string())
+        })?
+        .to_path_buf();
+
+    Ok(ProjectPaths { root, config })
+}
+
 /// Discover rules for a tool in the project
 pub fn discover_rules(project_root: &Path, tool: Tool) -> Result<Vec<PathBuf>> {
     let tool_dir = project_root.join(tool.directory());
@@ -151,6 +243,15 @@ pub fn rule_path(project_root: &Path, tool: Tool, rule_name: &str) -> Result<Pat
     Ok(path)
 }
 
+/// Display a path relative to the project root when possible
+#[must_use]
+pub fn display_relative(project_root: &Path, path: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
 /// Extract rule name from file path (removes extension)
 #[must_use]
 pub fn extract_rule_name(path: &Path) -> Option<String> {
@@ -201,6 +302,91 @@ pub fn ensure_directory<P: AsRef<Path>>(path: P) -> Result<()> {
     Ok(())
 }
 
+/// Files in the working tree, relative to `project_root` with `/` separators, sorted.
+///
+/// Asks git first, so `.gitignore` (at any level) is honored and untracked files
+/// are included. Outside a git repository, walks the tree instead, skipping
+/// `.git` and whatever the root `.gitignore` lists.
+pub fn project_files(project_root: &Path) -> Result<Vec<String>> {
+    let mut files = match git_files(project_root) {
+        Some(files) => files,
+        None => walk_files(project_root)?,
+    };
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn git_files(project_root: &Path) -> Option<Vec<String>> {
+    let output = std::process::Command::new("git")
+        .args([
+            "ls-files",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+            "-z",
+        ])
+        .current_dir(project_root)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let listing = String::from_utf8_lossy(&output.stdout);
+    Some(
+        listing
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            // Deleted but still tracked
+            .filter(|path| project_root.join(path).is_file())
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+fn walk_files(project_root: &Path) -> Result<Vec<String>> {
+    use crate::globs::{Dialect, GlobMatcher};
+
+    let gitignore_path = project_root.join(".gitignore");
+    let gitignore = if gitignore_path.is_file() {
+        fs::read_to_string(&gitignore_path)?
+    } else {
+        String::new()
+    };
+    let patterns = gitignore
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join(",");
+    let ignored = GlobMatcher::new(&patterns, Dialect::of(Tool::AgentSync));
+
+    let mut files = Vec::new();
+    let mut pending = vec![(project_root.to_path_buf(), String::new())];
+    while let Some((dir, prefix)) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let relative = format!("{prefix}{name}");
+            if name == ".git" || ignored.matches(&relative) {
+                continue;
+            }
+
+            // Symlinks are not followed, so they can't loop or leave the project
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push((entry.path(), format!("{relative}/")));
+            } else if file_type.is_file() {
+                files.push(relative);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
 #[cfg(test)]
 mod tests {
     // Allow expect/unwrap in tests for brevity
@@ -256,6 +442,105 @@ mod tests {
         assert!(result.is_ok() || matches!(result, Err(AgentSyncError::NotInitialized)));
     }
 
+    #[test]
+    fn test_find_project_root_from_subdirectory() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        fs::write(temp_dir.path().join(CONFIG_FILE), "{}").expect("test operation should succeed");
+        let nested = temp_dir.path().join("src/deeply/nested");
+        fs::create_dir_all(&nested).expect("test operation should succeed");
+
+        let root = find_project_root_from(&nested).expect("should find project root");
+        assert_eq!(root, temp_dir.path());
+    }
+
+    #[test]
+    fn test_find_project_root_stops_at_git_boundary() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        fs::write(temp_dir.path().join(CONFIG_FILE), "{}").expect("test operation should succeed");
+        let repo = temp_dir.path().join("repo");
+        fs::create_dir_all(repo.join(".git")).expect("test operation should succeed");
+        fs::create_dir_all(repo.join("src")).expect("test operation should succeed");
+
+        let result = find_project_root_from(&repo.join("src"));
+        assert!(matches!(result, Err(AgentSyncError::NotInitialized)));
+    }
+
+    #[test]
+    fn test_discover_project_roots_reports_nested_projects() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        let inner = temp_dir.path().join("packages/app");
+        fs::create_dir_all(inner.join("src")).expect("test operation should succeed");
+        fs::write(temp_dir.path().join(CONFIG_FILE), "{}").expect("test operation should succeed");
+        fs::write(inner.join(CONFIG_FILE), "{}").expect("test operation should succeed");
+
+        let roots = discover_project_roots(&inner.join("src"));
+        assert_eq!(roots, vec![inner.clone(), temp_dir.path().to_path_buf()]);
+
+        // The nearest project wins
+        let root = find_project_root_from(&inner.join("src")).expect("should find project root");
+        assert_eq!(root, inner);
+    }
+
+    #[test]
+    fn test_locate_project_with_explicit_config() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        let config_path = temp_dir.path().join("custom.json");
+        fs::write(&config_path, "{}").expect("test operation should succeed");
+
+        let paths = locate_project(Some(&config_path)).expect("should locate project");
+        assert_eq!(paths.root, temp_dir.path());
+        assert_eq!(paths.config, config_path);
+    }
+
+    #[test]
+    fn test_locate_project_with_config_directory() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        fs::write(temp_dir.path().join(CONFIG_FILE), "{}").expect("test operation should succeed");
+
+        let paths = locate_project(Some(temp_dir.path())).expect("should locate project");
+        assert_eq!(paths.root, temp_dir.path());
+        assert_eq!(paths.config, temp_dir.path().join(CONFIG_FILE));
+    }
+
+    #[test]
+    fn test_locate_project_missing_config() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        let config_path = temp_dir.path().join("missing.json");
+
+        let result = locate_project(Some(&config_path));
+        assert!(matches!(result, Err(AgentSyncError::ConfigNotFound { .. })));
+    }
+
+    #[test]
+    fn test_project_files_honors_gitignore() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for file in [
+            "src/main.rs",
+            "src/ui/app.tsx",
+            "target/debug/out",
+            "debug.log",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        fs::write(root.join(".gitignore"), "# build output\ntarget/\n*.log\n").unwrap();
+
+        let mut walked = walk_files(root).unwrap();
+        walked.sort();
+        assert_eq!(walked, [".gitignore", "src/main.rs", "src/ui/app.tsx"]);
+
+        // Inside a git repository, git does the listing
+        let git_init = std::process::Command::new("git")
+            .args(["init", "--quiet"])
+            .current_dir(root)
+            .status();
+        if git_init.is_ok_and(|status| status.success()) {
+            assert_eq!(project_files(root).unwrap(), walked);
+        }
+    }
+
     #[test]
     fn test_discover_rules_empty_directory() {
         let temp_dir = TempDir::new().expect("should create temp dir");
@@ -393,6 +678,19 @@ mod tests {
         assert_eq!(extract_rule_name(Path::new("/")), None);
     }
 
+    #[test]
+    fn test_display_relative() {
+        let root = Path::new("/project");
+        assert_eq!(
+            display_relative(root, Path::new("/project/.cursor/rules/a.mdc")),
+            ".cursor/rules/a.mdc"
+        );
+        assert_eq!(
+            display_relative(root, Path::new("/elsewhere/a.md")),
+            "/elsewhere/a.md"
+        );
+    }
+
     #[test]
     fn test_validate_rule_name() {
         // Valid names
@@ -0,0 +1,364 @@
+//! Glob matching in each tool's dialect.
+//!
+//! Every tool takes a comma-separated list of patterns, but they disagree on
+//! what some patterns mean:
+//!
+//! | Pattern         | Cursor                       | Windsurf, `AgentSync`        | Copilot (`applyTo`)      |
+//! |-----------------|------------------------------|------------------------------|--------------------------|
+//! | `*.ts` (no `/`) | file name, at any depth      | file name, at any depth      | project root only        |
+//! | `/src/*.ts`     | anchored to the project root | anchored to the project root | same as `src/*.ts`       |
+//! | `src/`          | everything under `src/`      | everything under `src/`      | nothing (needs `src/**`) |
+//! | `!**/*.gen.ts`  | excludes matching files      | unsupported                  | unsupported              |
+//! | `*.{ts,tsx}`    | alternatives                 | alternatives                 | alternatives             |
+//!
+//! Copilot's reading of bare patterns follows GitHub's documentation for
+//! `applyTo`, where `*.py` matches the `.py` files in the repository root and
+//! `**/*.py` those in every directory
+//! (<https://docs.github.com/en/copilot/customizing-copilot/adding-repository-custom-instructions-for-github-copilot>).
+//!
+//! Patterns with a `/` other than a trailing one are always relative to the
+//! project root. Paths are matched relative to the project root, with `/`
+//! separators.
+
+use crate::fs::Tool;
+use glob::{MatchOptions, Pattern};
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// How a tool interprets glob patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dialect {
+    pub tool: Tool,
+    /// Patterns without a `/` match the file name at any depth, as in `.gitignore`
+    pub basename_patterns: bool,
+    /// A trailing `/` matches everything under that directory
+    pub directory_patterns: bool,
+    /// A leading `!` excludes files matched by the other patterns
+    pub negation: bool,
+}
+
+impl Dialect {
+    /// The dialect `tool` reads its globs in
+    #[must_use]
+    pub const fn of(tool: Tool) -> Self {
+        match tool {
+            Tool::Cursor => Self {
+                tool,
+                basename_patterns: true,
+                directory_patterns: true,
+                negation: true,
+            },
+            Tool::Windsurf | Tool::AgentSync => Self {
+                tool,
+                basename_patterns: true,
+                directory_patterns: true,
+                negation: false,
+            },
+            Tool::Copilot => Self {
+                tool,
+                basename_patterns: false,
+                directory_patterns: false,
+                negation: false,
+            },
+        }
+    }
+}
+
+/// Split a comma-separated glob list, leaving commas inside `{...}` alone
+#[must_use]
+pub fn split_patterns(globs: &str) -> Vec<&str> {
+    let mut patterns = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, c) in globs.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                patterns.push(&globs[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    patterns.push(&globs[start..]);
+
+    patterns
+        .into_iter()
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .collect()
+}
+
+/// Expand `{a,b}` alternatives, which the `glob` crate does not support
+pub(crate) fn expand_braces(pattern: &str) -> Vec<String> {
+    let Some(open) = pattern.find('{') else {
+        return vec![pattern.to_string()];
+    };
+
+    let mut depth = 0usize;
+    let mut close = None;
+    let mut commas = Vec::new();
+    for (i, c) in pattern[open..].char_indices().map(|(i, c)| (i + open, c)) {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(i);
+                    break;
+                }
+            }
+            ',' if depth == 1 => commas.push(i),
+            _ => {}
+        }
+    }
+    let Some(close) = close else {
+        return vec![pattern.to_string()];
+    };
+
+    let (prefix, suffix) = (&pattern[..open], &pattern[close + 1..]);
+    let mut bounds = vec![open];
+    bounds.extend(commas);
+    bounds.push(close);
+
+    bounds
+        .windows(2)
+        .flat_map(|pair| {
+            let alternative = &pattern[pair[0] + 1..pair[1]];
+            expand_braces(&format!("{prefix}{alternative}{suffix}"))
+        })
+        .collect()
+}
+
+/// One pattern from the list, compiled for a dialect
+#[derive(Debug, Clone)]
+struct CompiledPattern {
+    source: String,
+    patterns: Vec<Pattern>,
+}
+
+impl CompiledPattern {
+    fn matches(&self, path: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| pattern.matches_with(path, MATCH_OPTIONS))
+    }
+}
+
+/// A glob list compiled for one tool's dialect
+#[derive(Debug, Clone)]
+pub struct GlobMatcher {
+    pub dialect: Dialect,
+    includes: Vec<CompiledPattern>,
+    excludes: Vec<CompiledPattern>,
+    /// Patterns the tool can't use, and why
+    pub issues: Vec<String>,
+}
+
+impl GlobMatcher {
+    /// Compile `globs` the way `dialect`'s tool reads them
+    #[must_use]
+    pub fn new(globs: &str, dialect: Dialect) -> Self {
+        let mut matcher = Self {
+            dialect,
+            includes: Vec::new(),
+            excludes: Vec::new(),
+            issues: Vec::new(),
+        };
+
+        for source in split_patterns(globs) {
+            let (negated, pattern) = match source.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, source),
+            };
+            if negated && !dialect.negation {
+                matcher.issues.push(format!(
+                    "`{source}`: {} does not support `!` exclusions; pattern ignored",
+                    dialect.tool
+                ));
+                continue;
+            }
+
+            let Some(compiled) = matcher.compile(source, pattern) else {
+                continue;
+            };
+            if negated {
+                matcher.excludes.push(compiled);
+            } else {
+                matcher.includes.push(compiled);
+            }
+        }
+
+        matcher
+    }
+
+    fn compile(&mut self, source: &str, pattern: &str) -> Option<CompiledPattern> {
+        let mut pattern = pattern.to_string();
+
+        if pattern.ends_with('/') {
+            if !self.dialect.directory_patterns {
+                self.issues.push(format!(
+                    "`{source}`: {} does not match directories; use `{pattern}**`",
+                    self.dialect.tool
+                ));
+                return None;
+            }
+            pattern.push_str("**");
+        }
+
+        let anchored = pattern.starts_with('/');
+        let pattern = pattern.trim_start_matches('/');
+        let pattern = if !anchored && self.dialect.basename_patterns && !pattern.contains('/') {
+            format!("**/{pattern}")
+        } else {
+            pattern.to_string()
+        };
+
+        let mut patterns = Vec::new();
+        for expanded in expand_braces(&pattern) {
+            match Pattern::new(&expanded) {
+                Ok(compiled) => patterns.push(compiled),
+                Err(e) => {
+                    self.issues
+                        .push(format!("`{source}`: invalid glob ({})", e.msg));
+                    return None;
+                }
+            }
+        }
+
+        Some(CompiledPattern {
+            source: source.to_string(),
+            patterns,
+        })
+    }
+
+    /// Whether there are no usable include patterns
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.includes.is_empty()
+    }
+
+    /// The first pattern that selects `path`, unless an exclusion removes it.
+    ///
+    /// `path` is relative to the project root and uses `/` separators.
+    #[must_use]
+    pub fn matching_pattern(&self, path: &str) -> Option<&str> {
+        if self.excludes.iter().any(|exclude| exclude.matches(path)) {
+            return None;
+        }
+        self.includes
+            .iter()
+            .find(|include| include.matches(path))
+            .map(|include| include.source.as_str())
+    }
+
+    /// Whether `path` is selected by the globs
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        self.matching_pattern(path).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(globs: &str, tool: Tool) -> GlobMatcher {
+        GlobMatcher::new(globs, Dialect::of(tool))
+    }
+
+    #[test]
+    fn test_split_patterns_keeps_braces() {
+        assert_eq!(
+            split_patterns("src/**/*.{ts,tsx}, tests/**/*.ts ,,"),
+            ["src/**/*.{ts,tsx}", "tests/**/*.ts"]
+        );
+        assert!(split_patterns("").is_empty());
+    }
+
+    #[test]
+    fn test_expand_braces() {
+        assert_eq!(expand_braces("*.{ts,tsx}"), ["*.ts", "*.tsx"]);
+        assert_eq!(
+            expand_braces("{src,lib}/*.{a,b}"),
+            ["src/*.a", "src/*.b", "lib/*.a", "lib/*.b"]
+        );
+        assert_eq!(expand_braces("a/{x,{y,z}}"), ["a/x", "a/y", "a/z"]);
+        assert_eq!(expand_braces("unclosed{"), ["unclosed{"]);
+    }
+
+    #[test]
+    fn test_recursive_patterns_match_every_dialect() {
+        for tool in [Tool::Cursor, Tool::Windsurf, Tool::Copilot] {
+            let globs = matcher("src/**/*.py", tool);
+            assert!(globs.matches("src/a.py"), "{tool}");
+            assert!(globs.matches("src/pkg/deep/b.py"), "{tool}");
+            assert!(!globs.matches("tests/a.py"), "{tool}");
+            assert!(!globs.matches("src/a.pyc"), "{tool}");
+
+            let everything = matcher("**", tool);
+            assert!(everything.matches("README.md"), "{tool}");
+            assert!(everything.matches("a/b/c.rs"), "{tool}");
+        }
+    }
+
+    #[test]
+    fn test_bare_patterns_differ_per_dialect() {
+        let cursor = matcher("*.ts", Tool::Cursor);
+        assert!(cursor.matches("index.ts"));
+        assert!(cursor.matches("src/app/index.ts"));
+
+        let copilot = matcher("*.ts", Tool::Copilot);
+        assert!(copilot.matches("index.ts"));
+        assert!(!copilot.matches("src/app/index.ts"));
+
+        // A leading slash anchors the pattern to the root
+        let anchored = matcher("/*.ts", Tool::Windsurf);
+        assert!(anchored.matches("index.ts"));
+        assert!(!anchored.matches("src/index.ts"));
+    }
+
+    #[test]
+    fn test_directory_patterns() {
+        let windsurf = matcher("docs/", Tool::Windsurf);
+        assert_eq!(
+            windsurf.matching_pattern("docs/guide/intro.md"),
+            Some("docs/")
+        );
+        assert!(windsurf.issues.is_empty());
+
+        let copilot = matcher("docs/", Tool::Copilot);
+        assert!(copilot.is_empty());
+        assert!(!copilot.matches("docs/intro.md"));
+        assert!(copilot.issues[0].contains("use `docs/**`"));
+    }
+
+    #[test]
+    fn test_negation() {
+        let cursor = matcher("**/*.ts,!**/*.gen.ts", Tool::Cursor);
+        assert_eq!(cursor.matching_pattern("src/a.ts"), Some("**/*.ts"));
+        assert!(!cursor.matches("src/a.gen.ts"));
+
+        let windsurf = matcher("**/*.ts,!**/*.gen.ts", Tool::Windsurf);
+        assert!(windsurf.matches("src/a.gen.ts"));
+        assert_eq!(windsurf.issues.len(), 1);
+        assert!(windsurf.issues[0].contains("does not support `!`"));
+    }
+
+    #[test]
+    fn test_braces_and_invalid_patterns() {
+        let globs = matcher("src/**/*.{ts,tsx}, [", Tool::Cursor);
+        assert_eq!(
+            globs.matching_pattern("src/ui/button.tsx"),
+            Some("src/**/*.{ts,tsx}")
+        );
+        assert_eq!(globs.issues.len(), 1);
+        assert!(globs.issues[0].contains("invalid glob"));
+    }
+}
@@ -6,17 +6,32 @@
 // Allow println/print in this module as it's a CLI tool
 #![allow(clippy::print_stdout)]
 
+pub mod budget;
 pub mod cli;
 pub mod commands;
 pub mod config;
 pub mod converter;
+pub mod detect;
 pub mod error;
+pub mod expect;
+pub mod explain;
 pub mod fs;
+pub mod globs;
+pub mod list;
+pub mod manage;
 pub mod models;
 pub mod parser;
 pub mod processor;
+pub mod roundtrip;
 pub mod security;
+pub mod show;
+pub mod split;
+pub mod status;
 pub mod sync;
+pub mod templates;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod validate;
 
 pub use cli::{Cli, Commands};
 pub use error::{AgentSyncError, Result};
@@ -24,6 +39,7 @@ pub use error::{AgentSyncError, Result};
 use tracing::{debug, info};
 
 /// Run the application with parsed CLI arguments
+#[allow(clippy::too_many_lines)] // One match arm per subcommand
 pub fn run(args: Cli) -> Result<()> {
     // Initialize logging
     init_logging(args.verbose);
@@ -31,44 +47,116 @@ pub fn run(args: Cli) -> Result<()> {
     debug!("Starting agentsync with args: {args:?}");
 
     match args.command {
-        Commands::Init => {
+        Commands::Init {
+            import,
+            no_import,
+            tools,
+            scaffold,
+            yes,
+        } => {
             info!("Running init command");
-            commands::run_init(args.verbose)
+            let options = commands::InitOptions {
+                import: if no_import {
+                    Some(commands::ImportSource::Skip)
+                } else {
+                    import.as_deref().map(str::parse).transpose()?
+                },
+                tools,
+                scaffold,
+                yes,
+            };
+            commands::run_init(args.config.as_deref(), &options, args.verbose, args.strict)
         }
         Commands::Sync { from, dry_run } => {
-            // Create sync options
             let options = sync::SyncOptions {
                 dry_run,
                 verbose: args.verbose,
+                strict: args.strict,
+                keep_existing: false,
+                ..Default::default()
             };
-
-            if let Some(tool_name) = from {
-                // Sync to AgentSync
-                info!("Running sync --from {tool_name}");
-
-                let project_root = fs::find_project_root()?;
-                let tool: fs::Tool = tool_name.parse()?;
-
-                println!("Syncing from {tool_name} to .agentsync/rules/...");
-                let result = sync::sync_from_tool(&project_root, tool, &options)?;
-                result.print_summary(dry_run);
-            } else {
-                // Sync from AgentSync
-                info!("Running sync to tools");
-
-                let project_root = fs::find_project_root()?;
-                let config = config::load_config(project_root.join("agentsync.json"))?;
-                config.validate()?;
-
-                println!("Syncing from .agentsync/rules/ to enabled tools...");
-                let result = sync::sync_to_tools(&project_root, &config.tools, &options)?;
-                result.print_summary(dry_run);
-            }
-            Ok(())
+            commands::run_sync(from.as_deref(), args.config.as_deref(), &options)
         }
-        Commands::Add { name } => {
+        Commands::Add {
+            name,
+            description,
+            globs,
+            targets,
+            mode,
+            from_file,
+            edit,
+            template,
+        } => {
             info!("Running add command for rule: {name}");
-            commands::run_add(&name, args.verbose)
+            let options = commands::AddOptions {
+                description,
+                globs,
+                targets,
+                mode,
+                from_file,
+                edit,
+                template,
+            };
+            commands::run_add(&name, args.config.as_deref(), &options, args.verbose)
+        }
+        Commands::Templates { json } => {
+            info!("Running templates command");
+            commands::run_templates(args.config.as_deref(), json)
+        }
+        Commands::Validate { tool, globs } => {
+            info!("Running validate command");
+            commands::run_validate(tool.as_deref(), args.config.as_deref(), globs, args.strict)
+        }
+        Commands::Status { json } => {
+            info!("Running status command");
+            commands::run_status(args.config.as_deref(), json)
+        }
+        Commands::List {
+            tool,
+            tag,
+            sort,
+            json,
+        } => {
+            info!("Running list command");
+            let filter = list::ListFilter {
+                tool: tool.as_deref().map(str::parse).transpose()?,
+                tag,
+            };
+            commands::run_list(args.config.as_deref(), &filter, sort, json)
+        }
+        Commands::Remove { name, yes, dry_run } => {
+            info!("Running remove command for rule: {name}");
+            commands::run_remove(&name, args.config.as_deref(), yes, dry_run)
+        }
+        Commands::Rename { old, new, dry_run } => {
+            info!("Running rename command: {old} -> {new}");
+            commands::run_rename(&old, &new, args.config.as_deref(), dry_run)
+        }
+        Commands::Show {
+            name,
+            tool,
+            annotate,
+        } => {
+            info!("Running show command for rule: {name}");
+            let tool = tool.as_deref().map(str::parse).transpose()?;
+            commands::run_show(&name, tool, args.config.as_deref(), annotate)
+        }
+        Commands::VerifyRoundtrip { json } => {
+            info!("Running verify-roundtrip command");
+            commands::run_verify_roundtrip(args.config.as_deref(), json)
+        }
+        Commands::Test { rule, json } => {
+            info!("Running test command");
+            commands::run_test(rule.as_deref(), args.config.as_deref(), json)
+        }
+        Commands::Explain { path, tool, json } => {
+            info!("Running explain command for path: {}", path.display());
+            let tool = tool.as_deref().map(str::parse).transpose()?;
+            commands::run_explain(&path, tool, args.config.as_deref(), json)
+        }
+        Commands::Convert { from, to } => {
+            info!("Running convert --from {from} --to {to}");
+            commands::run_convert(from.parse()?, to.parse()?)
         }
     }
 }
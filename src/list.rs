@@ -0,0 +1,424 @@
+//! Summaries of every source rule (`agentsync list`).
+
+use crate::Result;
+use crate::converter::{Activation, converted_activation};
+use crate::fs::{Tool, discover_rules, extract_rule_name, read_rule_file};
+use crate::models::AgentSyncRule;
+use crate::parser::parse_frontmatter;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// How `agentsync list` orders rules
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SortKey {
+    /// Alphabetically by rule name
+    #[default]
+    Name,
+    /// Rules synced to the most tools first
+    Targets,
+    /// Most recently edited first
+    Modified,
+}
+
+/// Which rules `agentsync list` shows
+#[derive(Debug, Clone, Default)]
+pub struct ListFilter {
+    /// Only rules synced to this tool
+    pub tool: Option<Tool>,
+    /// Only rules with this tag
+    pub tag: Option<String>,
+}
+
+/// Activation of a rule in one tool, after conversion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ToolActivation {
+    pub tool: String,
+    pub activation: Activation,
+    /// Globs the tool file ends up with (empty when not glob-based)
+    pub globs: String,
+}
+
+/// Everything `agentsync list` shows about one rule
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleSummary {
+    pub name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub targets: Vec<String>,
+    /// One entry per enabled tool the rule is synced to
+    pub tools: Vec<ToolActivation>,
+    #[serde(skip)]
+    modified: Option<SystemTime>,
+}
+
+/// Result of `list_rules`
+#[derive(Debug, Clone, Default)]
+pub struct RuleListing {
+    pub rules: Vec<RuleSummary>,
+    /// (rule name, error message) for rules that could not be parsed
+    pub errors: Vec<(String, String)>,
+}
+
+/// How `tool` activates `rule`, derived from the same conversion `sync` writes
+#[must_use]
+pub fn tool_activation(rule: &AgentSyncRule, tool: Tool) -> Option<ToolActivation> {
+    let (activation, globs) = converted_activation(rule, tool)?;
+
+    Some(ToolActivation {
+        tool: tool.name().to_string(),
+        activation,
+        globs,
+    })
+}
+
+/// Summarize every source rule, applying `filter` and `sort`.
+///
+/// Rules that fail to parse are reported in `errors` so one broken file does
+/// not hide the rest.
+pub fn list_rules(
+    project_root: &Path,
+    enabled_tools: &[Tool],
+    filter: &ListFilter,
+    sort: SortKey,
+) -> Result<RuleListing> {
+    let mut summaries = Vec::new();
+    let mut errors = Vec::new();
+
+    for path in discover_rules(project_root, Tool::AgentSync)? {
+        let Some(name) = extract_rule_name(&path) else {
+            continue;
+        };
+
+        let path_str = path.display().to_string();
+        let rule = match read_rule_file(&path)
+            .and_then(|content| parse_frontmatter::<AgentSyncRule>(&content, Some(&path_str)))
+        {
+            Ok(rule) => rule.frontmatter,
+            Err(e) => {
+                errors.push((name, e.to_string()));
+                continue;
+            }
+        };
+
+        let targets_all = rule.targets.iter().any(|t| t == "*");
+        let tools: Vec<ToolActivation> = enabled_tools
+            .iter()
+            .filter(|tool| filter.tool.is_none_or(|only| only == **tool))
+            .filter(|tool| targets_all || rule.targets.iter().any(|t| t == tool.name()))
+            .filter_map(|&tool| tool_activation(&rule, tool))
+            .collect();
+
+        if filter.tool.is_some() && tools.is_empty() {
+            continue;
+        }
+        if let Some(tag) = &filter.tag
+            && !rule.tags.contains(tag)
+        {
+            continue;
+        }
+
+        summaries.push(RuleSummary {
+            name,
+            description: rule.description,
+            tags: rule.tags,
+            targets: rule.targets,
+            tools,
+            modified: fs_err::metadata(&path).and_then(|m| m.modified()).ok(),
+        });
+    }
+
+    match sort {
+        SortKey::Name => summaries.sort_by(|a, b| a.name.cmp(&b.name)),
+        SortKey::Targets => summaries.sort_by(|a, b| {
+            b.tools
+                .len()
+                .cmp(&a.tools.len())
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+        SortKey::Modified => summaries.sort_by(|a, b| {
+            b.modified
+                .cmp(&a.modified)
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+    }
+
+    Ok(RuleListing {
+        rules: summaries,
+        errors,
+    })
+}
+
+/// Longest description shown in the table before it is cut short
+const DESCRIPTION_WIDTH: usize = 40;
+
+/// Cells of the `list` table, header row first: name, description, targets,
+/// one mode column per tool, and globs
+fn listing_table(summaries: &[RuleSummary]) -> Vec<Vec<String>> {
+    let mut tools: Vec<&str> = Vec::new();
+    for tool in summaries.iter().flat_map(|summary| &summary.tools) {
+        if !tools.contains(&tool.tool.as_str()) {
+            tools.push(&tool.tool);
+        }
+    }
+
+    let mut header = vec![
+        "RULE".to_string(),
+        "DESCRIPTION".to_string(),
+        "TARGETS".to_string(),
+    ];
+    header.extend(tools.iter().map(|tool| tool.to_uppercase()));
+    header.push("GLOBS".to_string());
+
+    let mut rows = vec![header];
+    for summary in summaries {
+        let mut row = vec![
+            if summary.tags.is_empty() {
+                summary.name.clone()
+            } else {
+                format!("{} [{}]", summary.name, summary.tags.join(", "))
+            },
+            truncate(&summary.description, DESCRIPTION_WIDTH),
+            summary.targets.join(","),
+        ];
+        row.extend(tools.iter().map(|&tool| {
+            summary
+                .tools
+                .iter()
+                .find(|activation| activation.tool == tool)
+                .map_or_else(
+                    || "-".to_string(),
+                    |activation| activation.activation.to_string().replace('_', " "),
+                )
+        }));
+
+        // Tools usually agree on the globs; list each distinct set once
+        let mut globs: Vec<&str> = Vec::new();
+        for tool in &summary.tools {
+            if !tool.globs.is_empty() && !globs.contains(&tool.globs.as_str()) {
+                globs.push(&tool.globs);
+            }
+        }
+        row.push(globs.join("; "));
+        rows.push(row);
+    }
+    rows
+}
+
+/// Cut `text` to `max` characters, marking the cut with `…`
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(max - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// Print rule summaries as an aligned table
+#[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
+pub fn print_listing(listing: &RuleListing) {
+    let RuleListing {
+        rules: summaries,
+        errors,
+    } = listing;
+
+    if summaries.is_empty() && errors.is_empty() {
+        println!("No matching rules in .agentsync/rules/");
+        return;
+    }
+
+    let rows = listing_table(summaries);
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let pad =
+        |cell: &str, width: usize| format!("{cell}{}", " ".repeat(width - cell.chars().count()));
+
+    for (index, row) in rows.iter().enumerate() {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, &width))| {
+                // The last column is left unpadded so lines carry no trailing spaces
+                let cell = if column + 1 == widths.len() {
+                    cell.clone()
+                } else {
+                    pad(cell, width)
+                };
+                match (index, column) {
+                    (0, _) | (_, 0) => cell.bold().to_string(),
+                    (_, 1) => cell.dimmed().to_string(),
+                    _ => cell,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+
+    for (name, error) in errors {
+        println!("{} {}", name.bold(), format!("error: {error}").red());
+    }
+
+    println!("\n{} rule(s)", summaries.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::project_with_rules;
+
+    const ALL_TOOLS: &[Tool] = &[Tool::Cursor, Tool::Copilot, Tool::Windsurf];
+
+    #[test]
+    fn test_tool_activation_per_tool() {
+        let temp_dir = project_with_rules(&[(
+            "rust",
+            "---\ntargets: [\"*\"]\ndescription: Rust\nglobs: \"**/*.rs\"\ncursor:\n  alwaysApply: true\nwindsurf:\n  trigger: model_decision\n---\n",
+        )]);
+
+        let RuleListing {
+            rules: summaries,
+            errors,
+        } = list_rules(
+            temp_dir.path(),
+            ALL_TOOLS,
+            &ListFilter::default(),
+            SortKey::Name,
+        )
+        .expect("should list rules");
+
+        assert!(errors.is_empty());
+        let tools = &summaries[0].tools;
+        assert_eq!(tools[0].activation, Activation::Always);
+        assert_eq!(tools[1].activation, Activation::Glob);
+        assert_eq!(tools[1].globs, "**/*.rs");
+        assert_eq!(tools[2].activation, Activation::ModelDecision);
+    }
+
+    #[test]
+    fn test_filter_by_tool_and_tag() {
+        let temp_dir = project_with_rules(&[
+            ("a", "---\ntargets: [\"cursor\"]\ntags: [\"lang\"]\n---\n"),
+            (
+                "b",
+                "---\ntargets: [\"windsurf\"]\ntags: [\"lang\", \"style\"]\n---\n",
+            ),
+            ("c", "---\ntargets: [\"*\"]\n---\n"),
+        ]);
+
+        let by_tool = ListFilter {
+            tool: Some(Tool::Cursor),
+            tag: None,
+        };
+        let summaries = list_rules(temp_dir.path(), ALL_TOOLS, &by_tool, SortKey::Name)
+            .expect("should list rules")
+            .rules;
+        let names: Vec<_> = summaries.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["a", "c"]);
+        assert!(summaries.iter().all(|s| s.tools.len() == 1));
+
+        let by_tag = ListFilter {
+            tool: None,
+            tag: Some("lang".to_string()),
+        };
+        let summaries = list_rules(temp_dir.path(), ALL_TOOLS, &by_tag, SortKey::Name)
+            .expect("should list rules")
+            .rules;
+        let names: Vec<_> = summaries.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_sort_by_targets_and_broken_rules() {
+        let temp_dir = project_with_rules(&[
+            ("narrow", "---\ntargets: [\"cursor\"]\n---\n"),
+            ("wide", "---\ntargets: [\"*\"]\n---\n"),
+            ("broken", "no frontmatter"),
+        ]);
+
+        let RuleListing {
+            rules: summaries,
+            errors,
+        } = list_rules(
+            temp_dir.path(),
+            ALL_TOOLS,
+            &ListFilter::default(),
+            SortKey::Targets,
+        )
+        .expect("should list rules");
+
+        let names: Vec<_> = summaries.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["wide", "narrow"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "broken");
+    }
+
+    #[test]
+    fn test_listing_table_columns() {
+        let temp_dir = project_with_rules(&[
+            (
+                "rust",
+                "---\ntargets: [\"*\"]\ndescription: Rust\ntags: [\"lang\"]\nactivation: glob\nglobs: \"**/*.rs\"\n---\n",
+            ),
+            (
+                "review",
+                "---\ntargets: [\"cursor\"]\ndescription: A review checklist that is far too long to show in full\nactivation: manual\n---\n",
+            ),
+        ]);
+        let summaries = list_rules(
+            temp_dir.path(),
+            ALL_TOOLS,
+            &ListFilter::default(),
+            SortKey::Name,
+        )
+        .expect("should list rules")
+        .rules;
+
+        let rows = listing_table(&summaries);
+        assert_eq!(
+            rows[0],
+            [
+                "RULE",
+                "DESCRIPTION",
+                "TARGETS",
+                "CURSOR",
+                "COPILOT",
+                "WINDSURF",
+                "GLOBS"
+            ]
+        );
+        assert_eq!(
+            rows[1],
+            [
+                "review",
+                "A review checklist that is far too long…",
+                "cursor",
+                "manual",
+                "-",
+                "-",
+                "",
+            ]
+        );
+        assert_eq!(
+            rows[2],
+            [
+                "rust [lang]",
+                "Rust",
+                "*",
+                "glob",
+                "glob",
+                "glob",
+                "**/*.rs"
+            ]
+        );
+    }
+}
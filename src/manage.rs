@@ -0,0 +1,296 @@
+//! Rule lifecycle operations that touch the source and every generated tool file (`remove`, `rename`).
+
+use crate::fs::{Tool, display_relative, rule_path, validate_rule_name};
+use crate::processor::get_processor;
+use crate::split::{existing_parts, part_name};
+use crate::{AgentSyncError, Result};
+use fs_err as fs;
+use std::path::{Path, PathBuf};
+
+/// One file belonging to a rule: the source in `.agentsync/rules/` or a generated tool file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleFile {
+    pub tool: Tool,
+    pub path: PathBuf,
+    /// Part number, for a tool file split from an oversized rule
+    pub part: Option<usize>,
+}
+
+/// Every path a rule can occupy, whether or not it exists: source first, then each tool
+pub fn rule_locations(project_root: &Path, name: &str) -> Result<Vec<RuleFile>> {
+    let mut files = vec![RuleFile {
+        tool: Tool::AgentSync,
+        path: rule_path(project_root, Tool::AgentSync, name)?,
+        part: None,
+    }];
+
+    for tool in Tool::SYNC_TARGETS {
+        files.push(RuleFile {
+            tool,
+            path: get_processor(tool).rule_path(project_root, name)?,
+            part: None,
+        });
+    }
+
+    Ok(files)
+}
+
+/// Files on disk that belong to `name`, including split parts, failing if there are none
+pub fn existing_rule_files(project_root: &Path, name: &str) -> Result<Vec<RuleFile>> {
+    let mut files = Vec::new();
+    for location in rule_locations(project_root, name)? {
+        let tool = location.tool;
+        if location.path.is_file() {
+            files.push(location);
+        }
+        if tool != Tool::AgentSync {
+            files.extend(existing_parts(project_root, tool, name)?.into_iter().map(
+                |(marker, path)| RuleFile {
+                    tool,
+                    path,
+                    part: Some(marker.index),
+                },
+            ));
+        }
+    }
+
+    if files.is_empty() {
+        return Err(AgentSyncError::RuleNotFound {
+            name: name.to_string(),
+        });
+    }
+
+    Ok(files)
+}
+
+/// Delete a rule's files, as returned by [`existing_rule_files`]
+pub fn remove_rule_files(files: &[RuleFile]) -> Result<()> {
+    for file in files {
+        fs::remove_file(&file.path)?;
+    }
+    Ok(())
+}
+
+/// A single file move performed by `rename`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleMove {
+    pub tool: Tool,
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+/// Work out every move needed to rename `old` to `new`, without touching the disk.
+///
+/// Split parts move to the same part numbers under `new`. Fails if `new` is not
+/// a valid rule name or if any file for `new` already exists in any tool, so a
+/// rename never overwrites or merges rules.
+pub fn plan_rename(project_root: &Path, old: &str, new: &str) -> Result<Vec<RuleMove>> {
+    validate_rule_name(new)?;
+
+    if old == new {
+        return Err(AgentSyncError::Other(format!(
+            "Rule '{old}' already has that name"
+        )));
+    }
+
+    let sources = existing_rule_files(project_root, old)?;
+    let destinations = rule_locations(project_root, new)?;
+
+    let mut moves = Vec::new();
+    for source in sources {
+        let to = match source.part {
+            Some(index) => {
+                get_processor(source.tool).rule_path(project_root, &part_name(new, index))?
+            }
+            None => match destinations.iter().find(|dest| dest.tool == source.tool) {
+                Some(dest) => dest.path.clone(),
+                None => continue,
+            },
+        };
+        moves.push(RuleMove {
+            tool: source.tool,
+            from: source.path,
+            to,
+        });
+    }
+
+    let mut taken: Vec<PathBuf> = match existing_rule_files(project_root, new) {
+        Ok(files) => files.into_iter().map(|file| file.path).collect(),
+        Err(AgentSyncError::RuleNotFound { .. }) => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    for step in &moves {
+        if step.to.exists() && !taken.contains(&step.to) {
+            taken.push(step.to.clone());
+        }
+    }
+    if !taken.is_empty() {
+        let conflicts: Vec<String> = taken
+            .iter()
+            .map(|path| display_relative(project_root, path))
+            .collect();
+        return Err(AgentSyncError::Other(format!(
+            "Cannot rename '{old}' to '{new}': these files already exist:\n  {}",
+            conflicts.join("\n  ")
+        )));
+    }
+
+    Ok(moves)
+}
+
+/// Apply moves from [`plan_rename`] as one unit.
+///
+/// If any move fails, the moves already made are reverted before returning the error.
+pub fn apply_moves(moves: &[RuleMove]) -> Result<()> {
+    for (done, step) in moves.iter().enumerate() {
+        if let Err(e) = fs::rename(&step.from, &step.to) {
+            for undo in moves[..done].iter().rev() {
+                if let Err(undo_err) = fs::rename(&undo.to, &undo.from) {
+                    tracing::warn!("Failed to roll back rename: {undo_err}");
+                }
+            }
+            return Err(e.into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project_with_files(paths: &[&str]) -> TempDir {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        for path in paths {
+            let full = temp_dir.path().join(path);
+            fs::create_dir_all(full.parent().expect("path should have a parent"))
+                .expect("test operation should succeed");
+            fs::write(full, "---\n---\n").expect("test operation should succeed");
+        }
+        temp_dir
+    }
+
+    #[test]
+    fn test_rule_locations_cover_every_tool() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+
+        let locations = rule_locations(temp_dir.path(), "style").expect("should resolve paths");
+
+        let tools: Vec<Tool> = locations.iter().map(|file| file.tool).collect();
+        assert_eq!(
+            tools,
+            [Tool::AgentSync, Tool::Cursor, Tool::Copilot, Tool::Windsurf]
+        );
+        assert!(
+            locations[2]
+                .path
+                .ends_with(".github/instructions/style.instructions.md")
+        );
+    }
+
+    #[test]
+    fn test_existing_rule_files_and_remove() {
+        let temp_dir = project_with_files(&[
+            ".agentsync/rules/style.md",
+            ".cursor/rules/style.mdc",
+            ".windsurf/rules/other.md",
+        ]);
+        let root = temp_dir.path();
+
+        let files = existing_rule_files(root, "style").expect("should find files");
+        assert_eq!(files.len(), 2);
+
+        remove_rule_files(&files).expect("should remove files");
+        assert!(!root.join(".agentsync/rules/style.md").exists());
+        assert!(!root.join(".cursor/rules/style.mdc").exists());
+        assert!(root.join(".windsurf/rules/other.md").exists());
+    }
+
+    #[test]
+    fn test_rename_moves_every_file() {
+        let temp_dir = project_with_files(&[
+            ".agentsync/rules/old-name.md",
+            ".cursor/rules/old-name.mdc",
+            ".github/instructions/old-name.instructions.md",
+        ]);
+        let root = temp_dir.path();
+
+        let moves = plan_rename(root, "old-name", "new-name").expect("should plan rename");
+        assert_eq!(moves.len(), 3);
+        apply_moves(&moves).expect("should rename");
+
+        assert!(root.join(".agentsync/rules/new-name.md").exists());
+        assert!(root.join(".cursor/rules/new-name.mdc").exists());
+        assert!(
+            root.join(".github/instructions/new-name.instructions.md")
+                .exists()
+        );
+        assert!(!root.join(".agentsync/rules/old-name.md").exists());
+        assert!(!root.join(".cursor/rules/old-name.mdc").exists());
+    }
+
+    #[test]
+    fn test_rename_conflict_changes_nothing() {
+        let temp_dir = project_with_files(&[
+            ".agentsync/rules/old-name.md",
+            ".cursor/rules/old-name.mdc",
+            ".windsurf/rules/new-name.md",
+        ]);
+        let root = temp_dir.path();
+
+        let err = plan_rename(root, "old-name", "new-name").expect_err("should detect conflict");
+        assert!(err.to_string().contains("new-name.md"));
+
+        assert!(root.join(".agentsync/rules/old-name.md").exists());
+        assert!(root.join(".cursor/rules/old-name.mdc").exists());
+    }
+
+    #[test]
+    fn test_rename_validates_new_name() {
+        let temp_dir = project_with_files(&[".agentsync/rules/old-name.md"]);
+
+        let result = plan_rename(temp_dir.path(), "old-name", "Bad Name");
+        assert!(matches!(
+            result,
+            Err(AgentSyncError::InvalidRuleName { .. })
+        ));
+
+        let same = plan_rename(temp_dir.path(), "old-name", "old-name");
+        assert!(same.is_err());
+    }
+
+    #[test]
+    fn test_apply_moves_rolls_back_on_failure() {
+        let temp_dir = project_with_files(&[".agentsync/rules/a.md", ".cursor/rules/a.mdc"]);
+        let root = temp_dir.path();
+
+        let moves = vec![
+            RuleMove {
+                tool: Tool::AgentSync,
+                from: root.join(".agentsync/rules/a.md"),
+                to: root.join(".agentsync/rules/b.md"),
+            },
+            RuleMove {
+                tool: Tool::Cursor,
+                from: root.join(".cursor/rules/missing.mdc"),
+                to: root.join(".cursor/rules/b.mdc"),
+            },
+        ];
+
+        assert!(apply_moves(&moves).is_err());
+        assert!(root.join(".agentsync/rules/a.md").exists());
+        assert!(!root.join(".agentsync/rules/b.md").exists());
+    }
+
+    #[test]
+    fn test_existing_rule_files_not_found() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+
+        let result = existing_rule_files(temp_dir.path(), "missing");
+        assert!(matches!(result, Err(AgentSyncError::RuleNotFound { .. })));
+
+        let traversal = existing_rule_files(temp_dir.path(), "../escape");
+        assert!(traversal.is_err());
+    }
+}
@@ -1,10 +1,11 @@
 //! Data models for AgentSync and tool-specific rule formats.
 
 use serde::{Deserialize, Serialize};
-use strum_macros::{Display, EnumString};
+use std::collections::BTreeMap;
+use strum_macros::{Display, EnumString, VariantNames};
 
 /// Windsurf trigger mode: Manual, `AlwaysOn`, `ModelDecision`, or Glob
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EnumString, Display)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, EnumString, Display, VariantNames)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum WindsurfTrigger {
@@ -20,6 +21,43 @@ impl Default for WindsurfTrigger {
     }
 }
 
+/// How a tool decides to load a rule into the agent's context
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumString,
+    VariantNames,
+    clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[value(rename_all = "snake_case")]
+pub enum Activation {
+    /// Always in context
+    Always,
+    /// Attached when a matching file is in context
+    Glob,
+    /// Loaded when the agent decides the description is relevant
+    ModelDecision,
+    /// Only when explicitly mentioned
+    Manual,
+    /// Attached for matching files, and also loadable by the agent from the description
+    GlobOrModel,
+}
+
+impl Activation {
+    /// Whether the activation needs `globs`
+    pub const fn uses_globs(self) -> bool {
+        matches!(self, Self::Glob | Self::GlobOrModel)
+    }
+}
+
 /// AgentSync rule format (single source of truth in `.agentsync/rules/*.md`)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AgentSyncRule {
@@ -30,10 +68,27 @@ pub struct AgentSyncRule {
     #[serde(default)]
     pub description: String,
 
+    /// Free-form labels for organizing rules (e.g. `agentsync list --tag`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// How every tool loads the rule; tool sections then only supply globs.
+    /// Without it, each tool's section decides its own mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activation: Option<Activation>,
+
     /// Comma-separated glob patterns
     #[serde(default = "default_globs")]
     pub globs: String,
 
+    /// Comma-separated patterns taken out of what `globs` (or a tool's own globs) match
+    #[serde(
+        rename = "excludeGlobs",
+        default,
+        skip_serializing_if = "String::is_empty"
+    )]
+    pub exclude_globs: String,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<CursorConfig>,
 
@@ -42,6 +97,22 @@ pub struct AgentSyncRule {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub copilot: Option<CopilotConfig>,
+
+    /// Files the rule's globs must and must not match, checked by `agentsync test`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect: Option<GlobExpectations>,
+}
+
+/// Expected glob matches for a rule, as paths relative to the project root
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlobExpectations {
+    /// Paths every tool that attaches the rule by globs must match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matches: Vec<String>,
+
+    /// Paths no tool may apply the rule to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excludes: Vec<String>,
 }
 
 /// Cursor config
@@ -119,6 +190,106 @@ pub struct AgentSyncConfig {
     /// Base directories for monorepo support
     #[serde(rename = "baseDirs", default = "default_base_dirs")]
     pub base_dirs: Vec<String>,
+
+    /// Per-tool handling of rules the tool can't represent faithfully (default: approximate)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fidelity: BTreeMap<String, FidelityPolicy>,
+
+    /// Per-tool size limits for generated rules, over the built-in ones
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub limits: BTreeMap<String, ToolLimits>,
+}
+
+/// What `sync` does when a tool can't represent a rule faithfully
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Display, EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FidelityPolicy {
+    /// Write the closest equivalent and warn
+    #[default]
+    Approximate,
+    /// Don't write the rule for that tool
+    Skip,
+    /// Report an error and make `sync` fail
+    Fail,
+}
+
+/// Size limits for the rules generated for one tool; unset limits aren't checked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolLimits {
+    /// Characters in a single generated rule file
+    #[serde(
+        rename = "maxFileChars",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_file_chars: Option<usize>,
+
+    /// Characters across every generated rule file
+    #[serde(
+        rename = "maxTotalChars",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_total_chars: Option<usize>,
+
+    /// Approximate tokens across always-on rules, loaded into every request
+    #[serde(
+        rename = "maxAlwaysOnTokens",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_always_on_tokens: Option<usize>,
+
+    /// What `sync` does when a limit is exceeded (default: warn)
+    #[serde(rename = "onExceed", default)]
+    pub on_exceed: LimitPolicy,
+}
+
+impl ToolLimits {
+    /// Limits the tool enforces itself: Windsurf ignores rule files over 6,000
+    /// characters and truncates rules past 12,000 characters in total
+    #[must_use]
+    pub fn builtin(tool: &str) -> Self {
+        match tool {
+            "windsurf" => Self {
+                max_file_chars: Some(6000),
+                max_total_chars: Some(12000),
+                ..Self::default()
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// `self`, with limits it leaves unset taken from `fallback`
+    #[must_use]
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            max_file_chars: self.max_file_chars.or(fallback.max_file_chars),
+            max_total_chars: self.max_total_chars.or(fallback.max_total_chars),
+            max_always_on_tokens: self.max_always_on_tokens.or(fallback.max_always_on_tokens),
+            on_exceed: self.on_exceed,
+        }
+    }
+}
+
+/// What `sync` does when generated rules exceed a tool's size limits
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Display, EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LimitPolicy {
+    /// Write the rules and warn
+    #[default]
+    Warn,
+    /// Don't write rule files over the per-file limit, and make `sync` fail
+    Fail,
+    /// Split rule files over the per-file limit at headings into `name-1`, `name-2`...
+    Split,
 }
 
 /// Rule with frontmatter and markdown body
@@ -156,11 +327,31 @@ fn default_base_dirs() -> Vec<String> {
 }
 
 impl AgentSyncConfig {
-    /// Validate config (tools, baseDirs)
+    /// Fidelity policy configured for `tool`
+    pub fn fidelity_policy(&self, tool: &str) -> FidelityPolicy {
+        self.fidelity.get(tool).copied().unwrap_or_default()
+    }
+
+    /// Size limits for `tool`: the configured ones, then the built-in ones
+    pub fn tool_limits(&self, tool: &str) -> ToolLimits {
+        resolve_limits(&self.limits, tool)
+    }
+
+    /// Validate config (tools, baseDirs), stopping at the first problem
     pub fn validate(&self) -> crate::Result<()> {
+        match self.validation_errors().into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Collect every config problem instead of stopping at the first
+    pub fn validation_errors(&self) -> Vec<crate::AgentSyncError> {
         // Validate tools with helpful error messages
         const VALID_TOOLS: &[&str] = &["cursor", "copilot", "windsurf"];
 
+        let mut errors = Vec::new();
+
         for tool in &self.tools {
             if !VALID_TOOLS.contains(&tool.as_str()) {
                 // Provide suggestions for typos
@@ -182,14 +373,34 @@ impl AgentSyncConfig {
                     error_msg.push_str(hint);
                 }
 
-                return Err(crate::AgentSyncError::ConfigError { error: error_msg });
+                errors.push(crate::AgentSyncError::ConfigError { error: error_msg });
+            }
+        }
+
+        for (section, tool) in self
+            .fidelity
+            .keys()
+            .map(|tool| ("fidelity", tool))
+            .chain(self.limits.keys().map(|tool| ("limits", tool)))
+        {
+            if !VALID_TOOLS.contains(&tool.as_str()) {
+                errors.push(crate::AgentSyncError::ConfigError {
+                    error: format!(
+                        "Invalid tool name in '{}': '{}'\n\nValid tools: {}",
+                        section,
+                        tool,
+                        VALID_TOOLS.join(", ")
+                    ),
+                });
             }
         }
 
         // Validate base_dirs using security module
-        crate::security::validate_base_dirs(&self.base_dirs)?;
+        if let Err(err) = crate::security::validate_base_dirs(&self.base_dirs) {
+            errors.push(err);
+        }
 
-        Ok(())
+        errors
     }
 }
 
@@ -198,14 +409,40 @@ impl Default for AgentSyncConfig {
         Self {
             tools: default_tools(),
             base_dirs: default_base_dirs(),
+            fidelity: BTreeMap::new(),
+            limits: BTreeMap::new(),
         }
     }
 }
 
+/// Size limits for `tool` from a `limits` map, falling back to the built-in ones
+pub fn resolve_limits(configured: &BTreeMap<String, ToolLimits>, tool: &str) -> ToolLimits {
+    configured
+        .get(tool)
+        .copied()
+        .unwrap_or_default()
+        .or(ToolLimits::builtin(tool))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_activation_cli_names_match_frontmatter() {
+        use clap::ValueEnum;
+
+        for activation in Activation::value_variants() {
+            let cli_name = activation
+                .to_possible_value()
+                .expect("no variant is hidden")
+                .get_name()
+                .to_string();
+            assert_eq!(cli_name, activation.to_string());
+            assert_eq!(cli_name.parse::<Activation>().ok(), Some(*activation));
+        }
+    }
+
     #[test]
     fn test_agentsync_config_validation() {
         let config = AgentSyncConfig::default();
@@ -214,13 +451,81 @@ mod tests {
         let invalid_config = AgentSyncConfig {
             tools: vec!["invalid".to_string()],
             base_dirs: vec![".".to_string()],
+            ..Default::default()
         };
         assert!(invalid_config.validate().is_err());
 
         let empty_dirs_config = AgentSyncConfig {
             tools: vec!["cursor".to_string()],
             base_dirs: vec![],
+            ..Default::default()
         };
         assert!(empty_dirs_config.validate().is_err());
     }
+
+    #[test]
+    fn test_fidelity_policy_config() {
+        let config: AgentSyncConfig =
+            serde_json::from_str(r#"{"fidelity": {"copilot": "skip", "cursor": "fail"}}"#)
+                .expect("should parse fidelity policies");
+        assert_eq!(config.fidelity_policy("copilot"), FidelityPolicy::Skip);
+        assert_eq!(config.fidelity_policy("cursor"), FidelityPolicy::Fail);
+        assert_eq!(
+            config.fidelity_policy("windsurf"),
+            FidelityPolicy::Approximate
+        );
+        assert!(config.validate().is_ok());
+
+        let typo: AgentSyncConfig =
+            serde_json::from_str(r#"{"fidelity": {"copilto": "skip"}}"#).expect("should parse");
+        assert!(typo.validate().is_err());
+
+        let unknown =
+            serde_json::from_str::<AgentSyncConfig>(r#"{"fidelity": {"copilot": "drop"}}"#);
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn test_tool_limits_config() {
+        let config: AgentSyncConfig = serde_json::from_str(
+            r#"{"limits": {"windsurf": {"maxFileChars": 4000, "onExceed": "fail"}, "cursor": {"maxAlwaysOnTokens": 1500}}}"#,
+        )
+        .expect("should parse limits");
+        assert!(config.validate().is_ok());
+
+        // Unset Windsurf limits keep the built-in value
+        let windsurf = config.tool_limits("windsurf");
+        assert_eq!(windsurf.max_file_chars, Some(4000));
+        assert_eq!(windsurf.max_total_chars, Some(12000));
+        assert_eq!(windsurf.on_exceed, LimitPolicy::Fail);
+
+        let cursor = config.tool_limits("cursor");
+        assert_eq!(cursor.max_always_on_tokens, Some(1500));
+        assert_eq!(cursor.max_file_chars, None);
+        assert_eq!(cursor.on_exceed, LimitPolicy::Warn);
+
+        assert_eq!(
+            AgentSyncConfig::default().tool_limits("copilot"),
+            ToolLimits::default()
+        );
+
+        let typo = serde_json::from_str::<AgentSyncConfig>(
+            r#"{"limits": {"windsurf": {"maxChars": 4000}}}"#,
+        );
+        assert!(typo.is_err());
+        let unknown_tool: AgentSyncConfig =
+            serde_json::from_str(r#"{"limits": {"codeium": {}}}"#).expect("should parse");
+        assert!(unknown_tool.validate().is_err());
+    }
+
+    #[test]
+    fn test_agentsync_config_validation_errors_collects_all() {
+        let config = AgentSyncConfig {
+            tools: vec!["cursr".to_string(), "codeium".to_string()],
+            base_dirs: vec![],
+            ..Default::default()
+        };
+        assert_eq!(config.validation_errors().len(), 3);
+        assert!(AgentSyncConfig::default().validation_errors().is_empty());
+    }
 }
@@ -1,43 +1,124 @@
 //! Parse markdown files with YAML frontmatter between `---` delimiters.
 
 use crate::models::{
-    AgentSyncRule, CopilotConfig, CopilotRule, CursorConfig, CursorRule, Rule, WindsurfConfig,
-    WindsurfRule, WindsurfTrigger,
+    Activation, AgentSyncRule, CopilotConfig, CopilotRule, CursorConfig, CursorRule,
+    GlobExpectations, Rule, WindsurfConfig, WindsurfRule, WindsurfTrigger,
 };
 use crate::{AgentSyncError, Result};
 use std::collections::HashMap;
+use std::fmt;
 use std::str::FromStr;
+use strum::VariantNames;
 
 /// Trait for parsing frontmatter from key-value pairs
 pub trait ParseFrontmatter: Sized {
+    /// Known keys and their expected values. Nested keys use `parent:child`.
+    const FIELDS: &'static [(&'static str, ValueKind)];
+
     fn from_key_values(map: &HashMap<String, String>) -> Result<Self>;
 }
 
+/// Expected value of a frontmatter key, checked by [`check_frontmatter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// Free-form text
+    Text,
+    /// `true` or `false`
+    Bool,
+    /// Comma-separated glob patterns
+    Globs,
+    /// Windsurf trigger mode
+    Trigger,
+    /// Unified activation mode
+    Activation,
+    /// Tool names or `*`
+    Targets,
+    /// Parent of nested keys, must not have a value of its own
+    Section,
+}
+
 /// Trait for serializing frontmatter to key-value pairs
 pub trait SerializeFrontmatter {
     fn to_key_values(&self) -> Vec<(String, String)>;
 }
 
-/// Split frontmatter from markdown. Returns `(frontmatter_text, body)`.
-fn split_frontmatter(content: &str, filename: Option<&str>) -> Result<(String, String)> {
-    let content = content.trim_start();
+/// 1-based location of a frontmatter key in the source file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Frontmatter key-value pairs along with the source location of every key
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrontmatterMap {
+    /// Nested keys use `parent:child` notation
+    pub values: HashMap<String, String>,
+    pub spans: HashMap<String, Span>,
+    /// Lines that aren't `key: value` pairs, skipped with the reason why
+    pub skipped: Vec<(Span, String)>,
+}
+
+/// Build a located frontmatter error pointing at `column` of `source_line`
+fn frontmatter_error(
+    file: &str,
+    span: Span,
+    source_line: &str,
+    message: impl Into<String>,
+) -> AgentSyncError {
+    AgentSyncError::FrontmatterParse {
+        file: file.to_string(),
+        line: Some(span.line),
+        column: Some(span.column),
+        source_line: Some(source_line.to_string()),
+        message: message.into(),
+    }
+}
+
+/// 1-based column of the first non-whitespace character
+fn first_column(line: &str) -> usize {
+    line.chars().take_while(|c| c.is_whitespace()).count() + 1
+}
+
+/// Split frontmatter from markdown.
+///
+/// Returns `(frontmatter_text, body, first_line)` where `first_line` is the
+/// 1-based line number of the first frontmatter line in `content`.
+fn split_frontmatter(content: &str, filename: Option<&str>) -> Result<(String, String, usize)> {
+    let trimmed = content.trim_start();
     let file = filename.unwrap_or("unknown");
 
+    // Leading blank lines are skipped, but still count towards line numbers
+    let opening_line = content[..content.len() - trimmed.len()]
+        .matches('\n')
+        .count()
+        + 1;
+
     // Check if file starts with ---
-    if !content.starts_with("---") {
-        return Err(AgentSyncError::FrontmatterParse {
-            file: file.to_string(),
-            line: Some(1),
-            message: "Missing opening '---' delimiter".to_string(),
-        });
+    if !trimmed.starts_with("---") {
+        let source_line = trimmed.lines().next().unwrap_or_default();
+        return Err(frontmatter_error(
+            file,
+            Span {
+                line: opening_line,
+                column: 1,
+            },
+            source_line,
+            "Missing opening '---' delimiter",
+        ));
     }
 
     // Find the closing --- delimiter
-    let after_first = &content[3..]; // Skip first ---
+    let after_first = &trimmed[3..]; // Skip first ---
 
     if let Some(end_pos) = after_first.find("\n---") {
-        // Extract frontmatter (between the two --- markers)
-        let frontmatter = after_first[..end_pos].trim().to_string();
+        // Extract frontmatter (between the two --- markers), dropping the
+        // remainder of the opening delimiter line
+        let frontmatter = after_first[..end_pos]
+            .split_once('\n')
+            .map_or("", |(_, rest)| rest)
+            .trim_end()
+            .to_string();
 
         // Extract body (everything after the second ---)
         let body_start = end_pos + 4; // Skip \n---
@@ -47,13 +128,17 @@ fn split_frontmatter(content: &str, filename: Option<&str>) -> Result<(String, S
             String::new()
         };
 
-        Ok((frontmatter, body))
+        Ok((frontmatter, body, opening_line + 1))
     } else {
-        Err(AgentSyncError::FrontmatterParse {
-            file: file.to_string(),
-            line: None,
-            message: "Missing closing '---' delimiter".to_string(),
-        })
+        Err(frontmatter_error(
+            file,
+            Span {
+                line: opening_line,
+                column: 1,
+            },
+            "---",
+            "Missing closing '---' delimiter for the frontmatter opened here",
+        ))
     }
 }
 
@@ -82,6 +167,39 @@ fn parse_json_array(value: &str) -> String {
         .join(",")
 }
 
+/// Parse a YAML flow mapping (`{ key: value, ... }`) into its entries.
+///
+/// Returns `None` unless every entry is a `key: value` pair, so brace globs
+/// like `{src,lib}` are left alone.
+fn parse_flow_mapping(value: &str) -> Option<Vec<(String, String)>> {
+    let inner = value.strip_prefix('{')?.strip_suffix('}')?;
+
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in inner.char_indices().chain([(inner.len(), ',')]) {
+        match c {
+            '"' | '\'' if quote == Some(c) => quote = None,
+            '"' | '\'' if quote.is_none() => quote = Some(c),
+            '[' | '{' if quote.is_none() => depth += 1,
+            ']' | '}' if quote.is_none() => depth = depth.saturating_sub(1),
+            ',' if quote.is_none() && depth == 0 => {
+                let entry = inner[start..i].trim();
+                start = i + 1;
+                if entry.is_empty() {
+                    continue;
+                }
+                let (key, value) = entry.split_once(':')?;
+                entries.push((key.trim().to_string(), parse_json_array(unquote(value))));
+            }
+            _ => {}
+        }
+    }
+
+    (quote.is_none() && !entries.is_empty()).then_some(entries)
+}
+
 /// Join list items and insert into map
 fn finalize_list_items(
     map: &mut HashMap<String, String>,
@@ -96,63 +214,118 @@ fn finalize_list_items(
     }
 }
 
-/// Parse key-value pairs from frontmatter (supports nesting, lists, JSON arrays)
-fn parse_key_value_pairs(text: &str) -> HashMap<String, String> {
-    let mut map = HashMap::new();
+/// Parse key-value pairs from frontmatter (supports nesting, lists, JSON arrays).
+///
+/// `first_line` is the 1-based line number of the first line of `text` in the
+/// file, so that spans point at the original source. Lines that can't be read
+/// are skipped and recorded, for [`check_frontmatter`] to report.
+fn parse_key_value_pairs(text: &str, first_line: usize) -> FrontmatterMap {
+    let mut parsed = FrontmatterMap::default();
     let mut current_parent: Option<String> = None;
+    // Key that `- item` lines belong to: the last key with an empty value
+    let mut list_key: Option<String> = None;
     let mut list_items: Vec<String> = Vec::new();
 
-    for line in text.lines() {
+    for (offset, line) in text.lines().enumerate() {
         let trimmed = line.trim();
         if trimmed.is_empty() || trimmed.starts_with('#') {
             continue;
         }
 
         let indent_level = line.len() - line.trim_start().len();
+        let span = Span {
+            line: first_line + offset,
+            column: first_column(line),
+        };
 
         // Handle YAML list items (- value)
-        if trimmed.starts_with('-') && indent_level > 0 {
-            if current_parent.is_some() {
-                let item = unquote(&trimmed[1..]);
-                list_items.push(item.to_string());
+        if let Some(item) = trimmed.strip_prefix('-') {
+            if list_key.is_none() {
+                parsed.skipped.push((
+                    span,
+                    "List item without a parent key (expected `key:` on a previous line)"
+                        .to_string(),
+                ));
+                continue;
             }
+            list_items.push(unquote(item).to_string());
             continue;
         }
 
         // Finalize any pending list items when we encounter a non-list line
-        if !list_items.is_empty() && !trimmed.starts_with('-') {
-            finalize_list_items(&mut map, current_parent.as_ref(), &mut list_items);
-            current_parent = None;
+        if !list_items.is_empty() {
+            finalize_list_items(&mut parsed.values, list_key.as_ref(), &mut list_items);
+            if list_key == current_parent {
+                current_parent = None;
+            }
         }
+        list_key = None;
 
         // Split on first colon
-        if let Some((key, value)) = trimmed.split_once(':') {
-            let key = key.trim();
-            let value = unquote(value);
-
-            if indent_level == 0 {
-                // Top-level key: handle JSON arrays and regular values
-                let parsed_value = parse_json_array(value);
-                map.insert(key.to_string(), parsed_value);
-
-                // Track parent for nested values or lists
-                current_parent = if value.is_empty() {
-                    Some(key.to_string())
-                } else {
-                    None
-                };
-            } else if let Some(ref parent) = current_parent {
-                // Nested key under parent
-                let nested_key = format!("{parent}:{key}");
-                map.insert(nested_key, value.to_string());
+        let Some((key, value)) = trimmed.split_once(':') else {
+            parsed
+                .skipped
+                .push((span, format!("Expected `key: value`, found `{trimmed}`")));
+            continue;
+        };
+
+        let key = key.trim();
+        let raw_value = value.trim();
+        let value = unquote(value);
+
+        if key.is_empty() {
+            parsed
+                .skipped
+                .push((span, "Missing key before ':'".to_string()));
+            continue;
+        }
+
+        if indent_level == 0 {
+            current_parent = None;
+
+            // Inline nested keys: `key: { child: value, ... }`
+            if let Some(entries) = parse_flow_mapping(raw_value) {
+                for (child, child_value) in entries {
+                    let nested_key = format!("{key}:{child}");
+                    parsed.values.insert(nested_key.clone(), child_value);
+                    parsed.spans.insert(nested_key, span);
+                }
+                parsed.values.insert(key.to_string(), String::new());
+                parsed.spans.insert(key.to_string(), span);
+                continue;
+            }
+
+            // Top-level key: handle JSON arrays and regular values
+            let parsed_value = parse_json_array(value);
+            parsed.values.insert(key.to_string(), parsed_value);
+            parsed.spans.insert(key.to_string(), span);
+
+            // Track parent for nested values or lists
+            if value.is_empty() {
+                current_parent = Some(key.to_string());
+                list_key.clone_from(&current_parent);
+            }
+        } else if let Some(ref parent) = current_parent {
+            // Nested key under parent
+            let nested_key = format!("{parent}:{key}");
+            parsed
+                .values
+                .insert(nested_key.clone(), parse_json_array(value));
+            parsed.spans.insert(nested_key.clone(), span);
+            if value.is_empty() {
+                list_key = Some(nested_key);
             }
+        } else {
+            parsed
+                .skipped
+                .push((span, format!("Indented key `{key}` has no parent key")));
         }
     }
 
     // Finalize any remaining list items at the end
-    finalize_list_items(&mut map, current_parent.as_ref(), &mut list_items);
+    finalize_list_items(&mut parsed.values, list_key.as_ref(), &mut list_items);
 
-    map
+    parsed
 }
 
 /// Normalize glob patterns by removing spaces after commas
@@ -177,6 +350,12 @@ fn parse_bool(value: &str, default: bool) -> bool {
 }
 
 impl ParseFrontmatter for CursorRule {
+    const FIELDS: &'static [(&'static str, ValueKind)] = &[
+        ("description", ValueKind::Text),
+        ("alwaysApply", ValueKind::Bool),
+        ("globs", ValueKind::Globs),
+    ];
+
     fn from_key_values(map: &HashMap<String, String>) -> Result<Self> {
         Ok(Self {
             description: map.get("description").cloned().unwrap_or_default(),
@@ -201,6 +380,12 @@ impl SerializeFrontmatter for CursorRule {
 }
 
 impl ParseFrontmatter for WindsurfRule {
+    const FIELDS: &'static [(&'static str, ValueKind)] = &[
+        ("trigger", ValueKind::Trigger),
+        ("description", ValueKind::Text),
+        ("globs", ValueKind::Globs),
+    ];
+
     fn from_key_values(map: &HashMap<String, String>) -> Result<Self> {
         let trigger = map
             .get("trigger")
@@ -230,6 +415,11 @@ impl SerializeFrontmatter for WindsurfRule {
 }
 
 impl ParseFrontmatter for CopilotRule {
+    const FIELDS: &'static [(&'static str, ValueKind)] = &[
+        ("description", ValueKind::Text),
+        ("applyTo", ValueKind::Globs),
+    ];
+
     fn from_key_values(map: &HashMap<String, String>) -> Result<Self> {
         Ok(Self {
             description: map.get("description").cloned().unwrap_or_default(),
@@ -250,6 +440,26 @@ impl SerializeFrontmatter for CopilotRule {
 }
 
 impl ParseFrontmatter for AgentSyncRule {
+    const FIELDS: &'static [(&'static str, ValueKind)] = &[
+        ("targets", ValueKind::Targets),
+        ("description", ValueKind::Text),
+        ("tags", ValueKind::Text),
+        ("activation", ValueKind::Activation),
+        ("globs", ValueKind::Globs),
+        ("excludeGlobs", ValueKind::Globs),
+        ("cursor", ValueKind::Section),
+        ("cursor:alwaysApply", ValueKind::Bool),
+        ("cursor:globs", ValueKind::Globs),
+        ("windsurf", ValueKind::Section),
+        ("windsurf:trigger", ValueKind::Trigger),
+        ("windsurf:globs", ValueKind::Globs),
+        ("copilot", ValueKind::Section),
+        ("copilot:applyTo", ValueKind::Globs),
+        ("expect", ValueKind::Section),
+        ("expect:matches", ValueKind::Text),
+        ("expect:excludes", ValueKind::Text),
+    ];
+
     fn from_key_values(map: &HashMap<String, String>) -> Result<Self> {
         // Parse targets array
         let targets = map.get("targets").map_or_else(
@@ -262,6 +472,16 @@ impl ParseFrontmatter for AgentSyncRule {
             },
         );
 
+        let tags = map
+            .get("tags")
+            .map(|s| {
+                s.split(',')
+                    .map(|t| t.trim().to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
         // Parse nested cursor config
         let cursor = if map.contains_key("cursor") {
             let always_apply = parse_bool(
@@ -297,13 +517,37 @@ impl ParseFrontmatter for AgentSyncRule {
             None
         };
 
+        // Parse nested glob expectations
+        let expect = map.contains_key("expect").then(|| {
+            let paths = |key: &str| {
+                map.get(key)
+                    .map(|s| {
+                        s.split(',')
+                            .map(|path| path.trim().to_string())
+                            .filter(|path| !path.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            };
+            GlobExpectations {
+                matches: paths("expect:matches"),
+                excludes: paths("expect:excludes"),
+            }
+        });
+
         Ok(Self {
             targets,
             description: map.get("description").cloned().unwrap_or_default(),
+            tags,
+            activation: map
+                .get("activation")
+                .and_then(|s| Activation::from_str(s).ok()),
             globs: normalize_globs(map.get("globs").map_or("**/*", String::as_str)),
+            exclude_globs: normalize_globs(map.get("excludeGlobs").map_or("", String::as_str)),
             cursor,
             windsurf,
             copilot,
+            expect,
         })
     }
 }
@@ -320,8 +564,21 @@ impl SerializeFrontmatter for AgentSyncRule {
             pairs.push(("description".to_string(), self.description.clone()));
         }
 
+        // Tags
+        if !self.tags.is_empty() {
+            pairs.push(("tags".to_string(), self.tags.join(",")));
+        }
+
+        // Activation
+        if let Some(activation) = self.activation {
+            pairs.push(("activation".to_string(), activation.to_string()));
+        }
+
         // Globs
         pairs.push(("globs".to_string(), self.globs.clone()));
+        if !self.exclude_globs.is_empty() {
+            pairs.push(("excludeGlobs".to_string(), self.exclude_globs.clone()));
+        }
 
         // Nested cursor config
         if let Some(ref cursor) = self.cursor {
@@ -346,6 +603,17 @@ impl SerializeFrontmatter for AgentSyncRule {
             pairs.push(("copilot:applyTo".to_string(), copilot.apply_to.clone()));
         }
 
+        // Nested glob expectations
+        if let Some(ref expect) = self.expect {
+            pairs.push(("expect".to_string(), String::new()));
+            if !expect.matches.is_empty() {
+                pairs.push(("expect:matches".to_string(), expect.matches.join(",")));
+            }
+            if !expect.excludes.is_empty() {
+                pairs.push(("expect:excludes".to_string(), expect.excludes.join(",")));
+            }
+        }
+
         pairs
     }
 }
@@ -355,9 +623,9 @@ pub fn parse_frontmatter<T: ParseFrontmatter>(
     content: &str,
     filename: Option<&str>,
 ) -> Result<Rule<T>> {
-    let (frontmatter_str, body) = split_frontmatter(content, filename)?;
-    let map = parse_key_value_pairs(&frontmatter_str);
-    let frontmatter = T::from_key_values(&map)?;
+    let (frontmatter_str, body, first_line) = split_frontmatter(content, filename)?;
+    let map = parse_key_value_pairs(&frontmatter_str, first_line);
+    let frontmatter = T::from_key_values(&map.values)?;
 
     Ok(Rule {
         frontmatter,
@@ -365,6 +633,236 @@ pub fn parse_frontmatter<T: ParseFrontmatter>(
     })
 }
 
+/// Parse only the frontmatter of a markdown file, keeping the location of every key
+pub fn parse_frontmatter_map(content: &str, filename: Option<&str>) -> Result<FrontmatterMap> {
+    let (frontmatter_str, _, first_line) = split_frontmatter(content, filename)?;
+    Ok(parse_key_value_pairs(&frontmatter_str, first_line))
+}
+
+/// Problem found by [`check_frontmatter`]: an unknown key, invalid value, wrong
+/// type, or a line that isn't a `key: value` pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontmatterIssue {
+    pub file: String,
+    pub span: Span,
+    /// Offending frontmatter line
+    pub source_line: String,
+    pub message: String,
+}
+
+impl FrontmatterIssue {
+    /// Convert into a located parse error, used when issues are fatal (`--strict`)
+    #[must_use]
+    pub fn into_error(self) -> AgentSyncError {
+        frontmatter_error(&self.file, self.span, &self.source_line, self.message)
+    }
+}
+
+impl fmt::Display for FrontmatterIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.file, self.span.line, self.span.column, self.message
+        )
+    }
+}
+
+/// Valid tool names for `targets`
+const TARGET_NAMES: &[&str] = &["*", "cursor", "copilot", "windsurf"];
+
+/// Render a `parent:child` key the way users write it
+fn display_key(key: &str) -> String {
+    key.replace(':', ".")
+}
+
+/// Closest candidate by edit distance, if it's close enough to be a plausible typo
+fn closest_match<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let input_lower = input.to_lowercase();
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            (
+                candidate,
+                strsim::levenshtein(&input_lower, &candidate.to_lowercase()),
+            )
+        })
+        .filter(|(candidate, distance)| *distance <= (candidate.len() / 3).max(2))
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Suggest a known key for an unknown one, including keys placed under the wrong parent
+fn suggest_key(key: &str, fields: &[(&'static str, ValueKind)]) -> Option<String> {
+    let known = fields.iter().map(|(name, _)| *name);
+
+    if let Some(found) = closest_match(key, known.clone()) {
+        return Some(format!("did you mean `{}`?", display_key(found)));
+    }
+
+    // Same key nested under a different parent, e.g. `cursor.trigger`
+    let leaf = key.rsplit(':').next().unwrap_or(key);
+    known
+        .filter(|name| name.contains(':'))
+        .find(|name| name.rsplit(':').next() == Some(leaf))
+        .map(|name| {
+            let parent = name.split(':').next().unwrap_or_default();
+            format!("`{leaf}` belongs under `{parent}`")
+        })
+}
+
+/// Append a "did you mean" suggestion to a message when one was found
+fn with_suggestion(message: String, found: Option<&str>) -> String {
+    match found {
+        Some(found) => format!("{message} (did you mean `{found}`?)"),
+        None => message,
+    }
+}
+
+/// 1-based column where the value starts on a `key: value` line
+fn value_column(line: &str) -> Option<usize> {
+    let (key, value) = line.split_once(':')?;
+    let leading = value.chars().take_while(|c| c.is_whitespace()).count();
+    Some(key.chars().count() + 1 + leading + 1)
+}
+
+/// Check a single value against its expected kind, returning a message if it's invalid
+fn check_value(key: &str, value: &str, kind: ValueKind) -> Option<String> {
+    let shown = display_key(key);
+    match kind {
+        ValueKind::Text | ValueKind::Globs => None,
+        ValueKind::Bool => {
+            if value.is_empty() || matches!(value.to_lowercase().as_str(), "true" | "false") {
+                None
+            } else {
+                Some(format!(
+                    "Invalid value `{value}` for `{shown}`: expected `true` or `false`"
+                ))
+            }
+        }
+        ValueKind::Trigger => {
+            if WindsurfTrigger::from_str(value).is_ok() {
+                return None;
+            }
+            let message = format!(
+                "Invalid value `{value}` for `{shown}`: expected one of {}",
+                WindsurfTrigger::VARIANTS.join(", ")
+            );
+            let found = closest_match(value, WindsurfTrigger::VARIANTS.iter().copied());
+            Some(with_suggestion(message, found))
+        }
+        ValueKind::Activation => {
+            if Activation::from_str(value).is_ok() {
+                return None;
+            }
+            let message = format!(
+                "Invalid value `{value}` for `{shown}`: expected one of {}",
+                Activation::VARIANTS.join(", ")
+            );
+            let found = closest_match(value, Activation::VARIANTS.iter().copied());
+            Some(with_suggestion(message, found))
+        }
+        ValueKind::Targets if value.trim().is_empty() => Some(format!(
+            "`{shown}` must not be empty: list tool names, or `*` for every tool"
+        )),
+        ValueKind::Targets if value.split(',').any(|target| target.trim().is_empty()) => {
+            Some(format!("`{shown}` must not contain empty entries"))
+        }
+        ValueKind::Targets => value
+            .split(',')
+            .map(str::trim)
+            .find(|target| !TARGET_NAMES.contains(target))
+            .map(|target| {
+                let message = format!(
+                    "Invalid target `{target}` in `{shown}`: expected one of {}",
+                    TARGET_NAMES.join(", ")
+                );
+                with_suggestion(message, closest_match(target, TARGET_NAMES.iter().copied()))
+            }),
+        ValueKind::Section => {
+            if value.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "`{shown}` must contain nested keys, found the value `{value}`"
+                ))
+            }
+        }
+    }
+}
+
+/// Check frontmatter against the known keys of `T`.
+///
+/// Missing `---` delimiters are returned as `Err`. Unknown keys, invalid enum
+/// values, wrong types and unreadable lines (which parsing skips) are returned
+/// as issues so callers can decide whether to warn or fail (`--strict`).
+pub fn check_frontmatter<T: ParseFrontmatter>(
+    content: &str,
+    filename: Option<&str>,
+) -> Result<Vec<FrontmatterIssue>> {
+    let file = filename.unwrap_or("unknown");
+    let map = parse_frontmatter_map(content, filename)?;
+    let source_lines: Vec<&str> = content.lines().collect();
+
+    let mut keys: Vec<(&String, &Span)> = map.spans.iter().collect();
+    keys.sort_by_key(|(_, span)| (span.line, span.column));
+
+    let mut issues = Vec::new();
+    for (key, span) in keys {
+        let source_line = source_lines.get(span.line - 1).copied().unwrap_or_default();
+        let value = map.values.get(key).map_or("", String::as_str);
+
+        let Some((_, kind)) = T::FIELDS.iter().find(|(name, _)| name == key) else {
+            let message = match suggest_key(key, T::FIELDS) {
+                Some(suggestion) => format!("Unknown key `{}`; {suggestion}", display_key(key)),
+                None => format!("Unknown key `{}`", display_key(key)),
+            };
+            issues.push(FrontmatterIssue {
+                file: file.to_string(),
+                span: *span,
+                source_line: source_line.to_string(),
+                message,
+            });
+            continue;
+        };
+
+        if let Some(message) = check_value(key, value, *kind) {
+            // Point at the value rather than the key when it's on the same line
+            let column = value_column(source_line)
+                .filter(|col| *col <= source_line.chars().count())
+                .unwrap_or(span.column);
+            issues.push(FrontmatterIssue {
+                file: file.to_string(),
+                span: Span {
+                    line: span.line,
+                    column,
+                },
+                source_line: source_line.to_string(),
+                message,
+            });
+        }
+    }
+
+    for (span, message) in &map.skipped {
+        issues.push(FrontmatterIssue {
+            file: file.to_string(),
+            span: *span,
+            source_line: source_lines
+                .get(span.line - 1)
+                .copied()
+                .unwrap_or_default()
+                .to_string(),
+            message: format!("{message}; line ignored"),
+        });
+    }
+    issues.sort_by_key(|issue| (issue.span.line, issue.span.column));
+
+    Ok(issues)
+}
+
 /// Serialize frontmatter and content to markdown
 pub fn serialize_frontmatter<T: SerializeFrontmatter>(rule: &Rule<T>) -> Result<String> {
     let pairs = rule.frontmatter.to_key_values();
@@ -426,11 +924,21 @@ alwaysApply: true
 This is the body.
 ";
 
-        let (frontmatter, body) =
+        let (frontmatter, body, first_line) =
             split_frontmatter(content, None).expect("should parse valid frontmatter");
         assert!(frontmatter.contains("description: Test rule"));
         assert!(frontmatter.contains("alwaysApply: true"));
         assert!(body.starts_with("# Test Content"));
+        assert_eq!(first_line, 2);
+    }
+
+    #[test]
+    fn test_split_frontmatter_counts_leading_blank_lines() {
+        let content = "\n\n---\ndescription: Test\n---\nBody\n";
+
+        let (_, _, first_line) =
+            split_frontmatter(content, None).expect("should parse valid frontmatter");
+        assert_eq!(first_line, 4);
     }
 
     #[test]
@@ -676,13 +1184,97 @@ copilot:
         assert_eq!(copilot.apply_to, "**/*.rs");
     }
 
+    #[test]
+    fn test_agentsync_tags_roundtrip() {
+        let content = "---\ntargets: [\"*\"]\ntags: [\"lang\", \"rust\"]\n---\n# Tags\n";
+        let rule: Rule<AgentSyncRule> = parse_frontmatter(content, None).expect("should parse");
+        assert_eq!(rule.frontmatter.tags, vec!["lang", "rust"]);
+
+        let serialized = serialize_frontmatter(&rule).expect("should serialize");
+        assert!(serialized.contains("tags: lang,rust"));
+
+        let untagged: Rule<AgentSyncRule> =
+            parse_frontmatter("---\ntargets: [\"*\"]\n---\n", None).expect("should parse");
+        assert!(untagged.frontmatter.tags.is_empty());
+        assert!(
+            !serialize_frontmatter(&untagged)
+                .expect("should serialize")
+                .contains("tags")
+        );
+    }
+
+    #[test]
+    fn test_agentsync_activation_roundtrip() {
+        let content = "---\ntargets: [\"*\"]\ndescription: Components\nactivation: glob_or_model\nglobs: **/*.tsx\n---\n# Components\n";
+        let rule: Rule<AgentSyncRule> = parse_frontmatter(content, None).expect("should parse");
+        assert_eq!(rule.frontmatter.activation, Some(Activation::GlobOrModel));
+
+        let serialized = serialize_frontmatter(&rule).expect("should serialize");
+        assert!(serialized.contains("activation: glob_or_model"));
+
+        let issues =
+            check_frontmatter::<AgentSyncRule>("---\nactivation: glob_or_modle\n---\n", None)
+                .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("did you mean `glob_or_model`?"));
+    }
+
+    #[test]
+    fn test_agentsync_exclude_globs_roundtrip() {
+        let content = "---\nglobs: \"**/*.ts\"\nexcludeGlobs: [\"**/*.gen.ts\", \"dist/\"]\n---\n";
+        let rule: Rule<AgentSyncRule> = parse_frontmatter(content, None).expect("should parse");
+        assert_eq!(rule.frontmatter.exclude_globs, "**/*.gen.ts,dist/");
+
+        let serialized = serialize_frontmatter(&rule).expect("should serialize");
+        assert!(serialized.contains("excludeGlobs: **/*.gen.ts,dist/"));
+        assert!(
+            check_frontmatter::<AgentSyncRule>(content, None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_agentsync_expect_roundtrip() {
+        let inline = "---\nglobs: src/**/*.py\nexpect: { matches: [\"src/a.py\", \"src/b.py\"], excludes: [\"tests/x.py\"] }\n---\n";
+        let block = "---\nglobs: src/**/*.py\nexpect:\n  matches:\n    - src/a.py\n    - \"src/b.py\"\n  excludes: [tests/x.py]\n---\n";
+        let expected = GlobExpectations {
+            matches: vec!["src/a.py".to_string(), "src/b.py".to_string()],
+            excludes: vec!["tests/x.py".to_string()],
+        };
+
+        for content in [inline, block] {
+            let rule: Rule<AgentSyncRule> = parse_frontmatter(content, None).expect("should parse");
+            assert_eq!(rule.frontmatter.expect.as_ref(), Some(&expected));
+            assert!(
+                check_frontmatter::<AgentSyncRule>(content, None)
+                    .unwrap()
+                    .is_empty()
+            );
+
+            let serialized = serialize_frontmatter(&rule).expect("should serialize");
+            let reparsed: Rule<AgentSyncRule> =
+                parse_frontmatter(&serialized, None).expect("should reparse");
+            assert_eq!(reparsed.frontmatter.expect, Some(expected.clone()));
+        }
+
+        // Brace globs are not mistaken for inline mappings
+        let braces: Rule<AgentSyncRule> =
+            parse_frontmatter("---\nglobs: \"{src,lib}/**\"\n---\n", None).expect("should parse");
+        assert_eq!(braces.frontmatter.globs, "{src,lib}/**");
+        assert!(braces.frontmatter.expect.is_none());
+    }
+
     #[test]
     fn test_roundtrip_agentsync() {
         let rule = Rule {
             frontmatter: AgentSyncRule {
                 targets: vec!["*".to_string()],
                 description: "Test".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.rs".to_string(),
+                exclude_globs: String::new(),
                 cursor: Some(CursorConfig {
                     always_apply: false,
                     globs: "**/*.rs".to_string(),
@@ -694,6 +1286,7 @@ copilot:
                 copilot: Some(CopilotConfig {
                     apply_to: "**/*.rs".to_string(),
                 }),
+                expect: None,
             },
             content: "# Test\n".to_string(),
         };
@@ -714,4 +1307,186 @@ copilot:
             rule2.frontmatter.cursor.as_ref().unwrap().globs
         );
     }
+
+    #[test]
+    fn test_parse_frontmatter_map_tracks_spans() {
+        let content = "---\ndescription: Test\ncursor:\n  alwaysApply: true\n---\nBody\n";
+
+        let map = parse_frontmatter_map(content, None).expect("should parse");
+        assert_eq!(map.spans["description"], Span { line: 2, column: 1 });
+        assert_eq!(map.spans["cursor"], Span { line: 3, column: 1 });
+        assert_eq!(map.spans["cursor:alwaysApply"], Span { line: 4, column: 3 });
+    }
+
+    #[test]
+    fn test_unreadable_line_is_skipped_and_reported() {
+        let content = "---\ndescription: Test\nglobs: **/*.rs\n  alwaysApply true\n---\nBody\n";
+
+        // Parsing ignores the line, as earlier versions did
+        let rule = parse_frontmatter::<CursorRule>(content, Some("rule.mdc"))
+            .expect("should skip the line without a colon");
+        assert_eq!(rule.frontmatter.description, "Test");
+
+        let issues = check_frontmatter::<CursorRule>(content, Some("rule.mdc")).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("alwaysApply true"));
+        assert!(issues[0].message.ends_with("line ignored"));
+
+        // Under `--strict` the issue becomes a located error
+        match issues[0].clone().into_error() {
+            AgentSyncError::FrontmatterParse {
+                file,
+                line,
+                column,
+                source_line,
+                ..
+            } => {
+                assert_eq!(file, "rule.mdc");
+                assert_eq!(line, Some(4));
+                assert_eq!(column, Some(3));
+                assert_eq!(source_line.as_deref(), Some("  alwaysApply true"));
+            }
+            err => unreachable!("Expected FrontmatterParse, got: {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_orphan_list_item_and_nested_key_are_skipped() {
+        let content = "---\ndescription: Test\n  - cursor\n  globs: **/*.rs\n---\n";
+
+        let rule = parse_frontmatter::<AgentSyncRule>(content, None).expect("should parse");
+        assert_eq!(rule.frontmatter.description, "Test");
+        assert_eq!(rule.frontmatter.globs, "**/*");
+
+        let issues = check_frontmatter::<AgentSyncRule>(content, None).unwrap();
+        assert_eq!(
+            issues.iter().map(|issue| issue.span).collect::<Vec<_>>(),
+            [Span { line: 3, column: 3 }, Span { line: 4, column: 3 }]
+        );
+        assert!(issues[0].message.contains("List item without a parent key"));
+        assert!(issues[1].message.contains("`globs` has no parent key"));
+    }
+
+    #[test]
+    fn test_parse_error_missing_closing_points_at_opening() {
+        let content = "\n---\ndescription: Test\n";
+
+        let err = parse_frontmatter::<CursorRule>(content, None)
+            .expect_err("should reject unclosed frontmatter");
+        assert!(matches!(
+            err,
+            AgentSyncError::FrontmatterParse { line: Some(2), .. }
+        ));
+    }
+
+    #[test]
+    fn test_check_frontmatter_clean() {
+        let content = "---\ndescription: Test\nalwaysApply: true\nglobs: **/*.rs\n---\nBody\n";
+
+        let issues = check_frontmatter::<CursorRule>(content, None).expect("should parse");
+        assert!(issues.is_empty(), "unexpected issues: {issues:?}");
+    }
+
+    #[test]
+    fn test_check_frontmatter_misspelled_key() {
+        let content = "---\ndescription: Test\nalwaysapply: true\n---\nBody\n";
+
+        let issues = check_frontmatter::<CursorRule>(content, Some("rule.mdc")).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].span, Span { line: 3, column: 1 });
+        assert!(issues[0].message.contains("Unknown key `alwaysapply`"));
+        assert!(issues[0].message.contains("did you mean `alwaysApply`?"));
+        assert!(issues[0].to_string().starts_with("rule.mdc:3:1: "));
+    }
+
+    #[test]
+    fn test_check_frontmatter_key_under_wrong_parent() {
+        let content = "---\ncursor:\n  trigger: always_on\n---\nBody\n";
+
+        let issues = check_frontmatter::<AgentSyncRule>(content, None).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("Unknown key `cursor.trigger`"));
+        assert!(
+            issues[0]
+                .message
+                .contains("`trigger` belongs under `windsurf`")
+        );
+    }
+
+    #[test]
+    fn test_check_frontmatter_invalid_trigger() {
+        let content = "---\ntrigger: always-on\n---\nBody\n";
+
+        let issues = check_frontmatter::<WindsurfRule>(content, None).unwrap();
+        assert_eq!(issues.len(), 1);
+        // Caret points at the value, not the key
+        assert_eq!(
+            issues[0].span,
+            Span {
+                line: 2,
+                column: 10
+            }
+        );
+        assert!(issues[0].message.contains("Invalid value `always-on`"));
+        assert!(issues[0].message.contains("did you mean `always_on`?"));
+    }
+
+    #[test]
+    fn test_check_frontmatter_wrong_types() {
+        let content = "---\ntargets: [\"cursr\"]\ncursor: yes\nwindsurf:\n  globs: **/*.rs\ncopilot:\n  applyTo: **\n---\n";
+
+        let issues = check_frontmatter::<AgentSyncRule>(content, None).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("Invalid target `cursr`"));
+        assert!(issues[0].message.contains("did you mean `cursor`?"));
+        assert!(
+            issues[1]
+                .message
+                .contains("`cursor` must contain nested keys")
+        );
+    }
+
+    #[test]
+    fn test_check_frontmatter_empty_targets() {
+        for content in [
+            "---\ntargets: []\n---\n",
+            "---\ntargets:\n---\n",
+            "---\ntargets: cursor,,windsurf\n---\n",
+        ] {
+            let issues = check_frontmatter::<AgentSyncRule>(content, None).unwrap();
+            assert_eq!(issues.len(), 1, "{content}");
+            assert!(issues[0].message.contains("must not"), "{content}");
+            assert!(!issues[0].message.contains("Invalid target"), "{content}");
+        }
+
+        let issues = check_frontmatter::<AgentSyncRule>("---\ntargets: []\n---\n", None).unwrap();
+        assert!(issues[0].message.contains("`targets` must not be empty"));
+    }
+
+    #[test]
+    fn test_check_frontmatter_invalid_bool() {
+        let content = "---\nalwaysApply: yes\n---\n";
+
+        let issues = check_frontmatter::<CursorRule>(content, None).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("expected `true` or `false`"));
+    }
+
+    #[test]
+    fn test_frontmatter_issue_into_error() {
+        let content = "---\nalwaysapply: true\n---\n";
+
+        let issue = check_frontmatter::<CursorRule>(content, Some("rule.mdc"))
+            .unwrap()
+            .remove(0);
+        let err = issue.into_error();
+        assert!(matches!(
+            err,
+            AgentSyncError::FrontmatterParse {
+                line: Some(2),
+                column: Some(1),
+                ..
+            }
+        ));
+    }
 }
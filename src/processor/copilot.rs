@@ -5,7 +5,9 @@ use crate::Result;
 use crate::converter::{agentsync_rule_to_copilot, copilot_rule_to_agentsync};
 use crate::fs::Tool;
 use crate::models::{AgentSyncRule, CopilotRule, Rule};
-use crate::parser::{parse_frontmatter, serialize_frontmatter};
+use crate::parser::{
+    FrontmatterIssue, check_frontmatter, parse_frontmatter, serialize_frontmatter,
+};
 
 /// Processor for GitHub Copilot
 pub struct CopilotProcessor;
@@ -24,6 +26,10 @@ impl Processor for CopilotProcessor {
         let copilot_rule: Rule<CopilotRule> = parse_frontmatter(content, Some(path))?;
         Ok(copilot_rule_to_agentsync(&copilot_rule))
     }
+
+    fn check_frontmatter(&self, content: &str, path: &str) -> Result<Vec<FrontmatterIssue>> {
+        check_frontmatter::<CopilotRule>(content, Some(path))
+    }
 }
 
 #[cfg(test)]
@@ -39,12 +45,16 @@ mod tests {
             frontmatter: AgentSyncRule {
                 targets: vec!["copilot".to_string()],
                 description: "Test rule".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.rs".to_string(),
+                exclude_globs: String::new(),
                 cursor: None,
                 windsurf: None,
                 copilot: Some(CopilotConfig {
                     apply_to: "**/*.rs".to_string(),
                 }),
+                expect: None,
             },
             content: "# Test Rule\n\nThis is a test.".to_string(),
         };
@@ -94,12 +104,16 @@ mod tests {
             frontmatter: AgentSyncRule {
                 targets: vec!["copilot".to_string()],
                 description: "Roundtrip test".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.go".to_string(),
+                exclude_globs: String::new(),
                 cursor: None,
                 windsurf: None,
                 copilot: Some(CopilotConfig {
                     apply_to: "**/*.go".to_string(),
                 }),
+                expect: None,
             },
             content: "# Roundtrip\n\nTest content.".to_string(),
         };
@@ -5,7 +5,9 @@ use crate::Result;
 use crate::converter::{agentsync_rule_to_cursor, cursor_rule_to_agentsync};
 use crate::fs::Tool;
 use crate::models::{AgentSyncRule, CursorRule, Rule};
-use crate::parser::{parse_frontmatter, serialize_frontmatter};
+use crate::parser::{
+    FrontmatterIssue, check_frontmatter, parse_frontmatter, serialize_frontmatter,
+};
 
 /// Processor for Cursor
 pub struct CursorProcessor;
@@ -24,6 +26,10 @@ impl Processor for CursorProcessor {
         let cursor_rule: Rule<CursorRule> = parse_frontmatter(content, Some(path))?;
         Ok(cursor_rule_to_agentsync(&cursor_rule))
     }
+
+    fn check_frontmatter(&self, content: &str, path: &str) -> Result<Vec<FrontmatterIssue>> {
+        check_frontmatter::<CursorRule>(content, Some(path))
+    }
 }
 
 #[cfg(test)]
@@ -39,13 +45,17 @@ mod tests {
             frontmatter: AgentSyncRule {
                 targets: vec!["cursor".to_string()],
                 description: "Test rule".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.rs".to_string(),
+                exclude_globs: String::new(),
                 cursor: Some(CursorConfig {
                     always_apply: false, // Use false to test description in frontmatter
                     globs: "**/*.rs".to_string(),
                 }),
                 windsurf: None,
                 copilot: None,
+                expect: None,
             },
             content: "# Test Rule\n\nThis is a test.".to_string(),
         };
@@ -97,13 +107,17 @@ mod tests {
             frontmatter: AgentSyncRule {
                 targets: vec!["cursor".to_string()],
                 description: "Roundtrip test".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.ts".to_string(),
+                exclude_globs: String::new(),
                 cursor: Some(CursorConfig {
                     always_apply: false,
                     globs: "**/*.ts".to_string(),
                 }),
                 windsurf: None,
                 copilot: None,
+                expect: None,
             },
             content: "# Roundtrip\n\nTest content.".to_string(),
         };
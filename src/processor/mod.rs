@@ -1,6 +1,7 @@
 //! Tool-specific processors for rule handling (Cursor, Copilot, Windsurf).
 
 use crate::models::{AgentSyncRule, Rule};
+use crate::parser::{FrontmatterIssue, parse_frontmatter, serialize_frontmatter};
 use crate::{Result, fs::Tool};
 use std::path::{Path, PathBuf};
 
@@ -22,6 +23,9 @@ pub trait Processor {
     /// Convert tool format to AgentSync
     fn convert_to_agentsync(&self, content: &str, path: &str) -> Result<Rule<AgentSyncRule>>;
 
+    /// Check tool frontmatter for unknown keys and invalid values
+    fn check_frontmatter(&self, content: &str, path: &str) -> Result<Vec<FrontmatterIssue>>;
+
     fn discover_rules(&self, project_root: &Path) -> Result<Vec<PathBuf>> {
         crate::fs::discover_rules(project_root, self.tool())
     }
@@ -49,10 +53,57 @@ pub fn get_processor(tool: Tool) -> Box<dyn Processor> {
     }
 }
 
+/// Convert rule file contents from one format to another, going through AgentSync.
+///
+/// `path` is only used in error messages. Either side may be `Tool::AgentSync`.
+pub fn convert(content: &str, path: &str, from: Tool, to: Tool) -> Result<String> {
+    let rule = match from {
+        Tool::AgentSync => parse_frontmatter::<AgentSyncRule>(content, Some(path))?,
+        tool => get_processor(tool).convert_to_agentsync(content, path)?,
+    };
+
+    match to {
+        Tool::AgentSync => serialize_frontmatter(&rule),
+        tool => get_processor(tool).convert_from_agentsync(&rule),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_convert_cursor_to_windsurf() {
+        let cursor = "---\ndescription: Python style\nalwaysApply: false\nglobs: \"**/*.py\"\n---\n\n# Python\n";
+
+        let windsurf =
+            convert(cursor, "<stdin>", Tool::Cursor, Tool::Windsurf).expect("should convert");
+
+        assert!(windsurf.contains("trigger: glob"));
+        assert!(windsurf.contains("globs: **/*.py"));
+        assert!(windsurf.contains("# Python"));
+    }
+
+    #[test]
+    fn test_convert_to_and_from_agentsync() {
+        let copilot = "---\ndescription: JS\napplyTo: \"**/*.js\"\n---\n\n# JS\n";
+
+        let agentsync =
+            convert(copilot, "<stdin>", Tool::Copilot, Tool::AgentSync).expect("should convert");
+        assert!(agentsync.contains("targets: *"));
+
+        let back =
+            convert(&agentsync, "<stdin>", Tool::AgentSync, Tool::Copilot).expect("should convert");
+        assert!(back.contains("applyTo: **/*.js"));
+    }
+
+    #[test]
+    fn test_convert_reports_parse_errors_with_path() {
+        let err = convert("no frontmatter", "<stdin>", Tool::Cursor, Tool::Windsurf)
+            .expect_err("should fail");
+        assert!(err.to_string().contains("<stdin>"));
+    }
+
     #[test]
     fn test_get_processor_cursor() {
         let processor = get_processor(Tool::Cursor);
@@ -5,7 +5,9 @@ use crate::Result;
 use crate::converter::{agentsync_rule_to_windsurf, windsurf_rule_to_agentsync};
 use crate::fs::Tool;
 use crate::models::{AgentSyncRule, Rule, WindsurfRule};
-use crate::parser::{parse_frontmatter, serialize_frontmatter};
+use crate::parser::{
+    FrontmatterIssue, check_frontmatter, parse_frontmatter, serialize_frontmatter,
+};
 
 /// Processor for Windsurf (.md files in .windsurf/rules/)
 pub struct WindsurfProcessor;
@@ -24,6 +26,10 @@ impl Processor for WindsurfProcessor {
         let windsurf_rule: Rule<WindsurfRule> = parse_frontmatter(content, Some(path))?;
         Ok(windsurf_rule_to_agentsync(&windsurf_rule))
     }
+
+    fn check_frontmatter(&self, content: &str, path: &str) -> Result<Vec<FrontmatterIssue>> {
+        check_frontmatter::<WindsurfRule>(content, Some(path))
+    }
 }
 
 #[cfg(test)]
@@ -39,13 +45,17 @@ mod tests {
             frontmatter: AgentSyncRule {
                 targets: vec!["windsurf".to_string()],
                 description: "Test rule".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.rs".to_string(),
+                exclude_globs: String::new(),
                 cursor: None,
                 windsurf: Some(WindsurfConfig {
                     trigger: WindsurfTrigger::Glob, // Use Glob to test description in frontmatter
                     globs: "**/*.rs".to_string(),
                 }),
                 copilot: None,
+                expect: None,
             },
             content: "# Test Rule\n\nThis is a test.".to_string(),
         };
@@ -97,13 +107,17 @@ mod tests {
             frontmatter: AgentSyncRule {
                 targets: vec!["windsurf".to_string()],
                 description: "Roundtrip test".to_string(),
+                tags: Vec::new(),
+                activation: None,
                 globs: "**/*.ts".to_string(),
+                exclude_globs: String::new(),
                 cursor: None,
                 windsurf: Some(WindsurfConfig {
                     trigger: WindsurfTrigger::Glob,
                     globs: "**/*.ts".to_string(),
                 }),
                 copilot: None,
+                expect: None,
             },
             content: "# Roundtrip\n\nTest content.".to_string(),
         };
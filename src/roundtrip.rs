@@ -0,0 +1,414 @@
+//! Check that rules survive a trip through each tool's format (`agentsync verify-roundtrip`).
+//!
+//! Every source rule is exported to a tool with `convert_from_agentsync` and read
+//! back with `convert_to_agentsync`, as `sync` followed by `sync --from` would.
+//! The rule is stable when the copy that comes back activates the same way, says
+//! the same thing, and produces the same output for every enabled tool.
+
+use crate::Result;
+use crate::converter::{
+    ConversionNote, NoteReason, agentsync_to_copilot, agentsync_to_cursor, agentsync_to_windsurf,
+    conversion_notes, intended_activation, normalize_globs,
+};
+use crate::fs::{Tool, discover_rules, extract_rule_name, read_rule_file};
+use crate::models::{AgentSyncRule, Rule};
+use crate::parser::parse_frontmatter;
+use crate::processor::get_processor;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::path::Path;
+
+/// How a rule came back from one tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundtripState {
+    /// Nothing changed
+    Stable,
+    /// Something changed, but only what the tool is known to drop or approximate
+    Lossy,
+    /// Something changed that the conversion should have preserved
+    Changed,
+}
+
+/// One field that differs after the round trip
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    /// `description`, `activation`, `body`, or a tool field such as `cursor.globs`
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Round trip of one rule through one tool
+#[derive(Debug, Clone, Serialize)]
+pub struct RoundtripCheck {
+    pub rule: String,
+    pub tool: String,
+    pub state: RoundtripState,
+    pub changes: Vec<FieldChange>,
+    /// Known drops and approximations for this rule in this tool
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Round trips of every targeted rule through every enabled tool
+#[derive(Debug, Clone, Serialize)]
+pub struct RoundtripReport {
+    pub tools: Vec<String>,
+    pub checks: Vec<RoundtripCheck>,
+    /// Source rules that could not be read or parsed, with the error
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<(String, String)>,
+}
+
+impl RoundtripReport {
+    /// Number of round trips that changed something unexpectedly
+    #[must_use]
+    pub fn changed_count(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.state == RoundtripState::Changed)
+            .count()
+    }
+
+    /// Whether every rule was readable and came back unchanged or as a known approximation
+    #[must_use]
+    pub fn is_stable(&self) -> bool {
+        self.errors.is_empty() && self.changed_count() == 0
+    }
+
+    /// Print one line per round trip, with the changed fields underneath
+    #[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
+    pub fn print(&self) {
+        if self.checks.is_empty() && self.errors.is_empty() {
+            println!("No rules found in .agentsync/rules/");
+            return;
+        }
+
+        for (rule, error) in &self.errors {
+            println!("{} {rule}: {}", "✗".red(), format!("error: {error}").red());
+        }
+
+        for check in &self.checks {
+            let label = format!("{} via {}", check.rule, check.tool);
+            match check.state {
+                RoundtripState::Stable => println!("{} {label}", "✓".green()),
+                RoundtripState::Lossy => {
+                    println!("{} {label} {}", "⚠".yellow(), "(lossy)".yellow());
+                    for warning in &check.warnings {
+                        println!("    {}", warning.dimmed());
+                    }
+                }
+                RoundtripState::Changed => println!("{} {label}", "✗".red()),
+            }
+            for change in &check.changes {
+                println!(
+                    "    {}: {} → {}",
+                    change.field.bold(),
+                    show_value(&change.before),
+                    show_value(&change.after)
+                );
+            }
+        }
+
+        let stable = self
+            .checks
+            .iter()
+            .filter(|check| check.state == RoundtripState::Stable)
+            .count();
+        println!(
+            "\n{stable} of {} round trip(s) stable, {} lossy, {} changed",
+            self.checks.len(),
+            self.checks
+                .iter()
+                .filter(|check| check.state == RoundtripState::Lossy)
+                .count(),
+            self.changed_count()
+        );
+    }
+}
+
+/// Quote a field value for display, shortening long bodies to their first line
+fn show_value(value: &str) -> String {
+    match value.lines().count() {
+        0 => "\"\"".dimmed().to_string(),
+        1 => format!("{value:?}"),
+        lines => format!(
+            "{:?} … ({lines} lines)",
+            value.lines().next().unwrap_or_default()
+        ),
+    }
+}
+
+/// Round-trip every source rule through each of `tools` it targets
+pub fn verify_roundtrip(project_root: &Path, tools: &[Tool]) -> Result<RoundtripReport> {
+    let mut report = RoundtripReport {
+        tools: tools.iter().map(|tool| tool.name().to_string()).collect(),
+        checks: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    for source_path in discover_rules(project_root, Tool::AgentSync)? {
+        let Some(name) = extract_rule_name(&source_path) else {
+            continue;
+        };
+
+        let path_str = source_path.display().to_string();
+        let parsed = read_rule_file(&source_path)
+            .and_then(|content| parse_frontmatter::<AgentSyncRule>(&content, Some(&path_str)));
+        let rule = match parsed {
+            Ok(rule) => rule,
+            Err(e) => {
+                report.errors.push((name, e.to_string()));
+                continue;
+            }
+        };
+
+        let targets = &rule.frontmatter.targets;
+        for &tool in tools {
+            if tool == Tool::AgentSync || !targets.iter().any(|t| t == "*" || t == tool.name()) {
+                continue;
+            }
+            report
+                .checks
+                .push(check_rule(&name, &rule, tool, tools, &path_str)?);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Send `rule` through `tool` and back, comparing what every enabled tool would receive
+fn check_rule(
+    name: &str,
+    rule: &Rule<AgentSyncRule>,
+    tool: Tool,
+    tools: &[Tool],
+    path: &str,
+) -> Result<RoundtripCheck> {
+    let processor = get_processor(tool);
+    let exported = processor.convert_from_agentsync(rule)?;
+    let returned = processor.convert_to_agentsync(&exported, path)?;
+
+    let changes = field_changes(rule, &returned, tools);
+    // Defaults only fill in missing sections; they don't lose anything
+    let losses: Vec<ConversionNote> = conversion_notes(&rule.frontmatter, tool)
+        .into_iter()
+        .filter(|note| !matches!(note.reason, NoteReason::Defaulted { .. }))
+        .collect();
+    let dropped = |field: &str| {
+        losses
+            .iter()
+            .any(|note| matches!(note.reason, NoteReason::Dropped { field: f } if f == field))
+    };
+    let approximated = losses
+        .iter()
+        .any(|note| matches!(note.reason, NoteReason::Approximated(_)));
+    let explained = |change: &FieldChange| match change.field.as_str() {
+        "body" => false,
+        "description" | "targets" => dropped(&change.field),
+        // Exclusions without `!` patterns are taken out of every tool's globs
+        "excludeGlobs" => dropped("excludeGlobs") || approximated,
+        field
+            if field
+                .split_once('.')
+                .is_some_and(|(_, leaf)| matches!(leaf, "globs" | "applyTo")) =>
+        {
+            approximated || dropped("globs") || dropped(field) || dropped("excludeGlobs")
+        }
+        _ => approximated,
+    };
+    let state = if changes.is_empty() {
+        RoundtripState::Stable
+    } else if changes.iter().all(explained) {
+        RoundtripState::Lossy
+    } else {
+        RoundtripState::Changed
+    };
+
+    Ok(RoundtripCheck {
+        rule: name.to_string(),
+        tool: tool.name().to_string(),
+        state,
+        changes,
+        warnings: losses.iter().map(ToString::to_string).collect(),
+    })
+}
+
+/// Fields that differ between `before` and `after`, in a stable order
+fn field_changes(
+    before: &Rule<AgentSyncRule>,
+    after: &Rule<AgentSyncRule>,
+    tools: &[Tool],
+) -> Vec<FieldChange> {
+    let before_fields = compared_fields(before, tools);
+    let after_fields = compared_fields(after, tools);
+
+    before_fields
+        .into_iter()
+        .zip(after_fields)
+        .filter(|((_, old), (_, new))| old != new)
+        .map(|((field, before), (_, after))| FieldChange {
+            field,
+            before,
+            after,
+        })
+        .collect()
+}
+
+/// What a rule means, as field name → value: its description, activation,
+/// targets, exclusions and body, plus the frontmatter each enabled tool would
+/// get from it. `tags` only label the source rule and are left out.
+fn compared_fields(rule: &Rule<AgentSyncRule>, tools: &[Tool]) -> Vec<(String, String)> {
+    let frontmatter = &rule.frontmatter;
+    let mut fields = vec![
+        ("description".to_string(), frontmatter.description.clone()),
+        (
+            "activation".to_string(),
+            intended_activation(frontmatter).to_string(),
+        ),
+        ("targets".to_string(), frontmatter.targets.join(",")),
+        (
+            "excludeGlobs".to_string(),
+            normalize_globs(&frontmatter.exclude_globs),
+        ),
+        ("body".to_string(), rule.content.trim().to_string()),
+    ];
+
+    for tool in tools {
+        let tool_fields = match tool {
+            Tool::AgentSync => continue,
+            Tool::Cursor => {
+                let cursor = agentsync_to_cursor(frontmatter);
+                vec![
+                    ("alwaysApply", cursor.always_apply.to_string()),
+                    ("globs", cursor.globs),
+                ]
+            }
+            Tool::Windsurf => {
+                let windsurf = agentsync_to_windsurf(frontmatter);
+                vec![
+                    ("trigger", windsurf.trigger.to_string()),
+                    ("globs", windsurf.globs),
+                ]
+            }
+            Tool::Copilot => vec![("applyTo", agentsync_to_copilot(frontmatter).apply_to)],
+        };
+        fields.extend(
+            tool_fields
+                .into_iter()
+                .map(|(field, value)| (format!("{tool}.{field}"), value)),
+        );
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::project_with_rules;
+
+    const ALL_TOOLS: [Tool; 3] = [Tool::Cursor, Tool::Windsurf, Tool::Copilot];
+
+    #[test]
+    fn test_glob_rule_is_stable_everywhere() {
+        let temp_dir = project_with_rules(&[(
+            "rust",
+            "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"**/*.rs\"\n---\n\n# Rust\n",
+        )]);
+
+        let report = verify_roundtrip(temp_dir.path(), &ALL_TOOLS).expect("should verify");
+        assert_eq!(report.checks.len(), 3);
+        assert!(report.is_stable());
+        assert!(
+            report
+                .checks
+                .iter()
+                .all(|check| check.state == RoundtripState::Stable),
+            "{report:#?}"
+        );
+    }
+
+    #[test]
+    fn test_lossy_and_changed_round_trips() {
+        let temp_dir = project_with_rules(&[
+            (
+                "agent",
+                "---\ntargets: [\"copilot\"]\ndescription: Reviews\nactivation: model_decision\n---\n\n# Review\n",
+            ),
+            (
+                "legacy",
+                "---\ntargets: [\"cursor\"]\ndescription: Components\nglobs: \"**/*.tsx\"\ncursor:\n  globs: \"**/*.tsx\"\n---\n\n# Components\n",
+            ),
+        ]);
+
+        let report = verify_roundtrip(temp_dir.path(), &ALL_TOOLS).expect("should verify");
+        assert_eq!(report.checks.len(), 2);
+
+        let agent = &report.checks[0];
+        assert_eq!(agent.tool, "copilot");
+        assert_eq!(agent.state, RoundtripState::Lossy);
+        assert!(!agent.warnings.is_empty());
+        assert!(
+            agent
+                .changes
+                .iter()
+                .any(|change| change.field == "activation")
+        );
+
+        let legacy = &report.checks[1];
+        assert_eq!(legacy.tool, "cursor");
+        assert_eq!(legacy.state, RoundtripState::Changed, "{legacy:#?}");
+        // Cursor can't tell Windsurf's default `model_decision` apart from the
+        // `glob_or_model` it imports, so re-exporting switches Windsurf to `glob`
+        assert_eq!(
+            legacy.changes,
+            [
+                FieldChange {
+                    field: "targets".to_string(),
+                    before: "cursor".to_string(),
+                    after: "*".to_string(),
+                },
+                FieldChange {
+                    field: "windsurf.trigger".to_string(),
+                    before: "model_decision".to_string(),
+                    after: "glob".to_string(),
+                }
+            ]
+        );
+        assert_eq!(report.changed_count(), 1);
+        assert!(!report.is_stable());
+    }
+
+    #[test]
+    fn test_dropped_targets_and_exclusions_are_known_losses() {
+        let temp_dir = project_with_rules(&[(
+            "scripts",
+            "---\ntargets: [\"windsurf\"]\nactivation: glob\nglobs: \"src/**/*.{ts,tsx}\"\nexcludeGlobs: \"**/*.tsx\"\n---\n\n# Scripts\n",
+        )]);
+
+        let report = verify_roundtrip(temp_dir.path(), &ALL_TOOLS).expect("should verify");
+        let check = &report.checks[0];
+        assert_eq!(check.state, RoundtripState::Lossy, "{check:#?}");
+        assert_eq!(
+            check
+                .changes
+                .iter()
+                .map(|change| change.field.as_str())
+                .collect::<Vec<_>>(),
+            ["targets", "excludeGlobs", "cursor.globs"]
+        );
+        assert!(report.is_stable());
+    }
+
+    #[test]
+    fn test_broken_rule_is_reported() {
+        let temp_dir = project_with_rules(&[("broken", "no frontmatter")]);
+
+        let report = verify_roundtrip(temp_dir.path(), &ALL_TOOLS).expect("should verify");
+        assert!(report.checks.is_empty());
+        assert_eq!(report.errors.len(), 1);
+        assert!(!report.is_stable());
+    }
+}
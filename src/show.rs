@@ -0,0 +1,116 @@
+//! Preview what each tool receives for a rule without writing anything (`agentsync show`).
+
+use crate::converter::{ConversionNote, conversion_notes};
+use crate::fs::{Tool, read_rule_file, rule_path};
+use crate::models::AgentSyncRule;
+use crate::parser::parse_frontmatter;
+use crate::processor::get_processor;
+use crate::{AgentSyncError, Result};
+use std::path::{Path, PathBuf};
+
+/// Converted output of one rule for one tool
+#[derive(Debug, Clone)]
+pub struct Preview {
+    pub tool: Tool,
+    /// Where `agentsync sync` would write the file
+    pub path: PathBuf,
+    /// Whether the rule's `targets` include this tool
+    pub targeted: bool,
+    pub content: String,
+    /// Fields dropped or defaulted by the conversion
+    pub notes: Vec<ConversionNote>,
+}
+
+/// Convert rule `name` for each of `tools`, in memory only
+pub fn preview_rule(project_root: &Path, name: &str, tools: &[Tool]) -> Result<Vec<Preview>> {
+    let source = rule_path(project_root, Tool::AgentSync, name)?;
+    if !source.is_file() {
+        return Err(AgentSyncError::RuleNotFound {
+            name: name.to_string(),
+        });
+    }
+
+    let path_str = source.display().to_string();
+    let rule = parse_frontmatter::<AgentSyncRule>(&read_rule_file(&source)?, Some(&path_str))?;
+    let targets = &rule.frontmatter.targets;
+
+    tools
+        .iter()
+        .filter(|tool| **tool != Tool::AgentSync)
+        .map(|&tool| {
+            let processor = get_processor(tool);
+            Ok(Preview {
+                tool,
+                path: processor.rule_path(project_root, name)?,
+                targeted: targets.iter().any(|t| t == "*" || t == tool.name()),
+                content: processor.convert_from_agentsync(&rule)?,
+                notes: conversion_notes(&rule.frontmatter, tool),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::NoteReason;
+    use crate::test_support::project_with_rules;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_preview_does_not_write() {
+        let temp_dir = project_with_rules(&[(
+            "style",
+            "---\ntargets: [\"windsurf\"]\ndescription: Style\nglobs: \"**/*.py\"\n---\n\n# Style\n",
+        )]);
+        let root = temp_dir.path();
+
+        let previews =
+            preview_rule(root, "style", &[Tool::Cursor, Tool::Windsurf]).expect("should preview");
+
+        assert_eq!(previews.len(), 2);
+        assert!(!previews[0].targeted);
+        assert!(previews[1].targeted);
+        assert!(previews[1].content.contains("trigger: model_decision"));
+        assert!(previews[1].content.contains("# Style"));
+        assert!(previews[1].notes.iter().any(|note| note.reason
+            == NoteReason::Defaulted {
+                field: "windsurf.trigger"
+            }));
+        assert!(!root.join(".windsurf").exists());
+        assert!(!root.join(".cursor").exists());
+    }
+
+    #[test]
+    fn test_preview_notes_dropped_fields() {
+        let temp_dir = project_with_rules(&[(
+            "style",
+            "---\ntargets: [\"*\"]\ndescription: Always\ntags: [\"core\"]\ncursor:\n  alwaysApply: true\n  globs: \"**/*.rs\"\n---\n",
+        )]);
+
+        let previews =
+            preview_rule(temp_dir.path(), "style", &[Tool::Cursor]).expect("should preview");
+        let notes = &previews[0].notes;
+
+        let dropped: Vec<&str> = notes
+            .iter()
+            .filter_map(|note| match note.reason {
+                NoteReason::Dropped { field } => Some(field),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(dropped, ["description", "cursor.globs", "tags"]);
+        assert_eq!(
+            notes[0].to_string(),
+            "`description` dropped: Cursor omits it for always-applied rules"
+        );
+    }
+
+    #[test]
+    fn test_preview_missing_rule() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+
+        let result = preview_rule(temp_dir.path(), "missing", &[Tool::Cursor]);
+        assert!(matches!(result, Err(AgentSyncError::RuleNotFound { .. })));
+    }
+}
@@ -0,0 +1,443 @@
+//! Splitting oversized rules into parts for tools with a per-file size limit.
+//!
+//! With `onExceed: split`, a rule whose generated file is over the tool's
+//! `maxFileChars` is written as `name-1`, `name-2`... instead. Each part has the
+//! rule's frontmatter, so the same activation, and a run of whole sections of
+//! the body, cut before headings. The first body line of a part is a marker
+//! such as `<!-- agentsync:part 2/3 -->`, which ties the file to `name`, so
+//! `status`, `remove`, `rename`, `sync --from` and later syncs treat the parts
+//! as one rule.
+//!
+//! A part name that is also the name of a source rule would overwrite that
+//! rule's file, so such a rule is written whole instead, over the limit, and a
+//! source rule's file is never taken for a part.
+
+use crate::Result;
+use crate::fs::{Tool, extract_rule_name, read_rule_file, rule_path};
+use crate::models::{AgentSyncRule, LimitPolicy, Rule, ToolLimits};
+use crate::processor::get_processor;
+use std::path::{Path, PathBuf};
+
+const MARKER_PREFIX: &str = "<!-- agentsync:part ";
+const MARKER_SUFFIX: &str = " -->";
+
+/// Position of a generated file within a split rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartMarker {
+    /// 1-based
+    pub index: usize,
+    pub count: usize,
+}
+
+impl PartMarker {
+    fn line(self) -> String {
+        format!(
+            "{MARKER_PREFIX}{}/{}{MARKER_SUFFIX}",
+            self.index, self.count
+        )
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let (index, count) = line
+            .strip_prefix(MARKER_PREFIX)?
+            .strip_suffix(MARKER_SUFFIX)?
+            .split_once('/')?;
+        let marker = Self {
+            index: index.parse().ok()?,
+            count: count.parse().ok()?,
+        };
+        (1..=marker.count).contains(&marker.index).then_some(marker)
+    }
+
+    /// The marker of a part file and the byte range of its line, which must be
+    /// the first line after the frontmatter
+    fn find(content: &str) -> Option<(Self, std::ops::Range<usize>)> {
+        let mut offset = 0;
+        let mut fences = 0;
+        for line in content.split_inclusive('\n') {
+            let text = line.trim_end();
+            if fences == 2 {
+                return Self::parse(text).map(|marker| (marker, offset..offset + line.len()));
+            }
+            if text == "---" {
+                fences += 1;
+            }
+            offset += line.len();
+        }
+        None
+    }
+}
+
+/// Name of the `index`th part of `rule_name`
+#[must_use]
+pub fn part_name(rule_name: &str, index: usize) -> String {
+    format!("{rule_name}-{index}")
+}
+
+/// The rule a tool file named `name` was split from, and its position in it
+#[must_use]
+pub fn part_of(name: &str, content: &str) -> Option<(String, PartMarker)> {
+    let (marker, _) = PartMarker::find(content)?;
+    let group = name.strip_suffix(&format!("-{}", marker.index))?;
+    Some((group.to_string(), marker))
+}
+
+/// Join the contents of a split rule's part files back into one rule file.
+///
+/// Returns `None` unless every part from 1 to the count is present once.
+#[must_use]
+pub fn join_parts(parts: &[String]) -> Option<String> {
+    let mut found: Vec<(PartMarker, std::ops::Range<usize>, &str)> = parts
+        .iter()
+        .map(|content| {
+            PartMarker::find(content).map(|(marker, range)| (marker, range, content.as_str()))
+        })
+        .collect::<Option<_>>()?;
+    found.sort_by_key(|(marker, ..)| marker.index);
+
+    let complete = found
+        .iter()
+        .enumerate()
+        .all(|(i, (marker, ..))| marker.index == i + 1 && marker.count == found.len());
+    if !complete {
+        return None;
+    }
+
+    let (_, first_marker, first) = found.first()?;
+    let mut joined = first[..first_marker.start].to_string();
+    for (_, range, content) in &found {
+        joined.push_str(&content[range.end..]);
+    }
+    Some(joined)
+}
+
+/// Part files of `rule_name` in `tool`, ordered by part number
+pub fn existing_parts(
+    project_root: &Path,
+    tool: Tool,
+    rule_name: &str,
+) -> Result<Vec<(PartMarker, PathBuf)>> {
+    let prefix = format!("{rule_name}-");
+    let mut parts = Vec::new();
+
+    for path in get_processor(tool).discover_rules(project_root)? {
+        let Some(name) = extract_rule_name(&path) else {
+            continue;
+        };
+        let numbered = name
+            .strip_prefix(&prefix)
+            .is_some_and(|index| index.parse::<usize>().is_ok());
+        if !numbered || is_source_rule(project_root, &name)? {
+            continue;
+        }
+        let content = read_rule_file(&path)?;
+        if let Some((group, marker)) = part_of(&name, &content)
+            && group == rule_name
+        {
+            parts.push((marker, path));
+        }
+    }
+
+    parts.sort_by_key(|(marker, _)| marker.index);
+    Ok(parts)
+}
+
+/// Whether `.agentsync/rules/` has a rule named `name`
+fn is_source_rule(project_root: &Path, name: &str) -> Result<bool> {
+    Ok(rule_path(project_root, Tool::AgentSync, name)?.is_file())
+}
+
+/// A file `sync` writes for a rule in one tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleOutput {
+    /// The rule name, or the part name for a split rule
+    pub name: String,
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// The files `sync` writes for a rule in one tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleOutputs {
+    pub files: Vec<RuleOutput>,
+    /// Source rule a part would have been named after, which kept the rule whole
+    pub clash: Option<String>,
+}
+
+/// The files `sync` writes for `rule` in `tool`: the converted rule, or its
+/// parts when `limits` ask to split rules over `maxFileChars`.
+///
+/// Rules without a heading to cut before, or with a part named like another
+/// source rule, stay whole, over the limit.
+pub fn rule_outputs(
+    project_root: &Path,
+    rule_name: &str,
+    rule: &Rule<AgentSyncRule>,
+    tool: Tool,
+    limits: &ToolLimits,
+) -> Result<RuleOutputs> {
+    let processor = get_processor(tool);
+    let content = processor.convert_from_agentsync(rule)?;
+    let oversized = limits
+        .max_file_chars
+        .filter(|&max| limits.on_exceed == LimitPolicy::Split && content.chars().count() > max);
+
+    let part = |index: usize, count: usize, body: &str| -> Rule<AgentSyncRule> {
+        Rule {
+            frontmatter: rule.frontmatter.clone(),
+            content: format!("{}\n{body}", PartMarker { index, count }.line()),
+        }
+    };
+    let bodies = match oversized {
+        Some(max) => {
+            // Frontmatter and marker, repeated in every part
+            let overhead = processor
+                .convert_from_agentsync(&part(99, 99, ""))?
+                .chars()
+                .count();
+            max.checked_sub(overhead)
+                .filter(|&budget| budget > 0)
+                .map(|budget| pack_sections(&rule.content, budget))
+                .unwrap_or_default()
+        }
+        None => Vec::new(),
+    };
+
+    let mut clash = None;
+    for index in 1..=bodies.len() {
+        let name = part_name(rule_name, index);
+        if is_source_rule(project_root, &name)? {
+            clash = Some(name);
+            break;
+        }
+    }
+
+    if bodies.len() < 2 || clash.is_some() {
+        return Ok(RuleOutputs {
+            files: vec![RuleOutput {
+                name: rule_name.to_string(),
+                path: processor.rule_path(project_root, rule_name)?,
+                content,
+            }],
+            clash,
+        });
+    }
+
+    let files = bodies
+        .iter()
+        .enumerate()
+        .map(|(i, body)| {
+            let name = part_name(rule_name, i + 1);
+            Ok(RuleOutput {
+                path: processor.rule_path(project_root, &name)?,
+                content: processor.convert_from_agentsync(&part(i + 1, bodies.len(), body))?,
+                name,
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(RuleOutputs { files, clash })
+}
+
+/// Files of `rule_name` in `tool` that `outputs` no longer include: the whole
+/// file once the rule is split, and parts left over from an earlier split
+pub fn stale_files(
+    project_root: &Path,
+    rule_name: &str,
+    tool: Tool,
+    outputs: &[RuleOutput],
+) -> Result<Vec<PathBuf>> {
+    let whole = get_processor(tool).rule_path(project_root, rule_name)?;
+    let candidates = std::iter::once(whole).filter(|path| path.is_file()).chain(
+        existing_parts(project_root, tool, rule_name)?
+            .into_iter()
+            .map(|(_, path)| path),
+    );
+
+    Ok(candidates
+        .filter(|path| !outputs.iter().any(|output| &output.path == path))
+        .collect())
+}
+
+/// Cut `body` before headings and pack the sections, in order, into as few
+/// parts of at most `budget` characters as possible
+fn pack_sections(body: &str, budget: usize) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for section in sections(body) {
+        let fits = current.chars().count() + section.chars().count() <= budget;
+        if !fits && !current.is_empty() {
+            parts.push(std::mem::take(&mut current));
+        }
+        current.push_str(section);
+    }
+    if !current.is_empty() || parts.is_empty() {
+        parts.push(current);
+    }
+
+    parts
+}
+
+/// `body` cut before every heading outside fenced code blocks
+fn sections(body: &str) -> Vec<&str> {
+    let mut starts = vec![0];
+    let mut offset = 0;
+    let mut in_code = false;
+
+    for line in body.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+        } else if !in_code && offset > 0 && is_heading(line) {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+    starts.push(body.len());
+
+    starts
+        .windows(2)
+        .map(|bounds| &body[bounds[0]..bounds[1]])
+        .collect()
+}
+
+fn is_heading(line: &str) -> bool {
+    let level = line.len() - line.trim_start_matches('#').len();
+    (1..=6).contains(&level) && line[level..].starts_with([' ', '\t', '\n', '\r'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = "\n# Style\n\nIntro.\n\n## Naming\n\n```md\n# not a heading\n```\n\n## Errors\n\nUse `?`.\n";
+
+    #[test]
+    fn test_sections_cut_before_headings() {
+        assert_eq!(
+            sections(BODY),
+            [
+                "\n",
+                "# Style\n\nIntro.\n\n",
+                "## Naming\n\n```md\n# not a heading\n```\n\n",
+                "## Errors\n\nUse `?`.\n",
+            ]
+        );
+        assert_eq!(sections("No headings."), ["No headings."]);
+        assert!(!is_heading("#hashtag\n"));
+    }
+
+    #[test]
+    fn test_pack_sections() {
+        let parts = pack_sections(BODY, 45);
+        assert_eq!(
+            parts,
+            [
+                "\n# Style\n\nIntro.\n\n",
+                "## Naming\n\n```md\n# not a heading\n```\n\n",
+                "## Errors\n\nUse `?`.\n",
+            ]
+        );
+        assert_eq!(parts.concat(), BODY);
+        assert_eq!(pack_sections(BODY, 1000), [BODY]);
+    }
+
+    #[test]
+    fn test_part_markers_join_back() {
+        let part = |index, count, body: &str| {
+            format!(
+                "---\ntrigger: always_on\n---\n{}\n{body}",
+                PartMarker { index, count }.line()
+            )
+        };
+        let parts = [part(2, 2, "## Errors\n"), part(1, 2, "\n# Style\n")];
+
+        assert_eq!(
+            part_of("style-2", &parts[0]),
+            Some(("style".to_string(), PartMarker { index: 2, count: 2 }))
+        );
+        assert_eq!(part_of("style", &parts[0]), None);
+        assert_eq!(part_of("style-1", "---\n---\n\n# Style\n"), None);
+
+        assert_eq!(
+            join_parts(&parts).as_deref(),
+            Some("---\ntrigger: always_on\n---\n\n# Style\n## Errors\n")
+        );
+        assert_eq!(join_parts(&parts[..1]), None);
+    }
+
+    #[test]
+    fn test_rule_outputs_split_over_limit() {
+        let temp_dir = tempfile::TempDir::new().expect("should create temp dir");
+        let rule = crate::parser::parse_frontmatter::<AgentSyncRule>(
+            &format!("---\ntargets: [\"*\"]\nactivation: always\n---\n{BODY}"),
+            None,
+        )
+        .expect("should parse");
+        let limits = |max| ToolLimits {
+            max_file_chars: Some(max),
+            on_exceed: LimitPolicy::Split,
+            ..ToolLimits::default()
+        };
+
+        let whole = rule_outputs(
+            temp_dir.path(),
+            "style",
+            &rule,
+            Tool::Windsurf,
+            &limits(1000),
+        )
+        .expect("should convert")
+        .files;
+        assert_eq!(whole.len(), 1);
+        assert_eq!(whole[0].name, "style");
+
+        let max = whole[0].content.chars().count() - 1;
+        let parts = rule_outputs(
+            temp_dir.path(),
+            "style",
+            &rule,
+            Tool::Windsurf,
+            &limits(max),
+        )
+        .expect("should convert")
+        .files;
+        assert_eq!(
+            parts
+                .iter()
+                .map(|part| part.name.as_str())
+                .collect::<Vec<_>>(),
+            ["style-1", "style-2", "style-3"]
+        );
+        assert!(parts.iter().all(|part| part.content.chars().count() <= max));
+        assert!(parts[2].path.ends_with(".windsurf/rules/style-3.md"));
+
+        let contents: Vec<String> = parts.into_iter().map(|part| part.content).collect();
+        assert_eq!(join_parts(&contents), Some(whole[0].content.clone()));
+
+        // Only the split policy splits
+        let warn = ToolLimits {
+            on_exceed: LimitPolicy::Warn,
+            ..limits(max)
+        };
+        let unsplit = rule_outputs(temp_dir.path(), "style", &rule, Tool::Windsurf, &warn)
+            .expect("should convert");
+        assert_eq!(unsplit.files, whole);
+        assert_eq!(unsplit.clash, None);
+
+        // A part may not take the name of another source rule
+        let rules_dir = temp_dir.path().join(".agentsync/rules");
+        fs_err::create_dir_all(&rules_dir).expect("should create rules directory");
+        fs_err::write(rules_dir.join("style-2.md"), "---\n---\n").expect("should write rule");
+        let clashing = rule_outputs(
+            temp_dir.path(),
+            "style",
+            &rule,
+            Tool::Windsurf,
+            &limits(max),
+        )
+        .expect("should convert");
+        assert_eq!(clashing.files, whole);
+        assert_eq!(clashing.clash.as_deref(), Some("style-2"));
+    }
+}
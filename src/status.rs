@@ -0,0 +1,524 @@
+//! Per-rule, per-tool sync status (`agentsync status`).
+
+use crate::Result;
+use crate::budget::BudgetReport;
+use crate::converter::{Activation, converted_activation, fidelity_warnings};
+use crate::fs::{Tool, discover_rules, extract_rule_name, read_rule_file};
+use crate::models::{AgentSyncRule, FidelityPolicy, Rule, ToolLimits, resolve_limits};
+use crate::parser::parse_frontmatter;
+use crate::processor::get_processor;
+use crate::split::{existing_parts, part_of, rule_outputs, stale_files};
+use crate::sync::{FileComparison, compare_file};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// State of one rule in one tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleState {
+    /// Tool file matches what `agentsync sync` would write
+    InSync,
+    /// Source rule changed since the tool file was written
+    SourceAhead,
+    /// Tool file was edited after the source rule
+    ToolModified,
+    /// Source rule targets the tool but the tool file does not exist
+    Missing,
+    /// Tool file exists but the source rule no longer targets the tool
+    Orphaned,
+    /// Tool file with no source rule in `.agentsync/rules/`
+    Unmanaged,
+    /// Source rule does not target the tool and no tool file exists
+    NotTargeted,
+    /// The tool's fidelity policy keeps the rule out and no tool file exists
+    Skipped,
+}
+
+impl RuleState {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::InSync => "in sync",
+            Self::SourceAhead => "source ahead",
+            Self::ToolModified => "tool modified",
+            Self::Missing => "missing",
+            Self::Orphaned => "orphaned",
+            Self::Unmanaged => "unmanaged",
+            Self::NotTargeted => "-",
+            Self::Skipped => "skipped",
+        }
+    }
+
+    /// Whether `agentsync sync` (or `sync --from`) has work to do for this cell
+    #[must_use]
+    pub const fn needs_attention(self) -> bool {
+        !matches!(self, Self::InSync | Self::NotTargeted | Self::Skipped)
+    }
+}
+
+/// Status of a single rule across every enabled tool
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleStatus {
+    pub name: String,
+    /// Whether the rule exists in `.agentsync/rules/`
+    pub has_source: bool,
+    /// Tool name → state
+    pub tools: BTreeMap<String, RuleState>,
+    /// Source rule could not be read or parsed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Status matrix for the whole project
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusReport {
+    pub tools: Vec<String>,
+    pub rules: Vec<RuleStatus>,
+    /// Size of what `sync` writes for each tool
+    pub budget: BudgetReport,
+}
+
+impl StatusReport {
+    /// Whether every rule is in sync with every tool
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.rules
+            .iter()
+            .all(|rule| rule.error.is_none() && !rule.tools.values().any(|s| s.needs_attention()))
+    }
+
+    /// Print the status as a compact colored matrix
+    #[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
+    pub fn print(&self) {
+        if self.rules.is_empty() {
+            println!("No rules found in .agentsync/rules/ or tool directories");
+            return;
+        }
+
+        let name_width = self
+            .rules
+            .iter()
+            .map(|rule| rule.name.len())
+            .chain(std::iter::once("RULE".len()))
+            .max()
+            .unwrap_or_default();
+        let cell_width = self
+            .tools
+            .iter()
+            .map(String::len)
+            .chain(std::iter::once(RuleState::ToolModified.label().len()))
+            .max()
+            .unwrap_or_default();
+
+        let header = self
+            .tools
+            .iter()
+            .map(|tool| format!("{tool:<cell_width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!(
+            "{}  {}",
+            format!("{:<name_width$}", "RULE").bold(),
+            header.trim_end().bold()
+        );
+
+        for rule in &self.rules {
+            let name = format!("{:<name_width$}", rule.name);
+
+            if let Some(error) = &rule.error {
+                println!("{}  {}", name, format!("error: {error}").red());
+                continue;
+            }
+
+            let cells = self
+                .tools
+                .iter()
+                .map(|tool| {
+                    let state = rule
+                        .tools
+                        .get(tool)
+                        .copied()
+                        .unwrap_or(RuleState::NotTargeted);
+                    colorize(state, &format!("{:<cell_width$}", state.label()))
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            println!("{name}  {}", cells.trim_end());
+        }
+
+        if self.is_clean() {
+            println!("\n✓ All rules are in sync");
+        } else {
+            println!(
+                "\n{}{} Run {} to push source changes, or {} to import tool edits",
+                "hint".cyan().bold(),
+                ":".bold(),
+                "`agentsync sync`".green(),
+                "`agentsync sync --from <tool>`".green()
+            );
+        }
+
+        self.budget.print("");
+    }
+}
+
+fn colorize(state: RuleState, text: &str) -> String {
+    match state {
+        RuleState::InSync => text.green().to_string(),
+        RuleState::SourceAhead => text.yellow().to_string(),
+        RuleState::ToolModified => text.magenta().to_string(),
+        RuleState::Missing => text.red().to_string(),
+        RuleState::Orphaned | RuleState::Unmanaged => text.cyan().to_string(),
+        RuleState::NotTargeted | RuleState::Skipped => text.dimmed().to_string(),
+    }
+}
+
+/// Compute the status of every rule for every enabled tool, measuring the
+/// generated rules against `limits`. Rules a `fidelity` policy keeps out of a
+/// tool are skipped there, as `sync` skips them.
+pub fn project_status(
+    project_root: &Path,
+    enabled_tools: &[Tool],
+    limits: &BTreeMap<String, ToolLimits>,
+    fidelity: &BTreeMap<String, FidelityPolicy>,
+) -> Result<StatusReport> {
+    let mut rules: BTreeMap<String, RuleStatus> = BTreeMap::new();
+    let mut budget = BudgetReport::default();
+
+    for source_path in discover_rules(project_root, Tool::AgentSync)? {
+        let Some(name) = extract_rule_name(&source_path) else {
+            continue;
+        };
+
+        let mut status = RuleStatus {
+            name: name.clone(),
+            has_source: true,
+            tools: BTreeMap::new(),
+            error: None,
+        };
+
+        let path_str = source_path.display().to_string();
+        let parsed = read_rule_file(&source_path)
+            .and_then(|content| parse_frontmatter::<AgentSyncRule>(&content, Some(&path_str)));
+
+        match parsed {
+            Ok(rule) => {
+                for &tool in enabled_tools {
+                    let policy = fidelity.get(tool.name()).copied().unwrap_or_default();
+                    let state = if is_blocked(&rule.frontmatter, tool, policy) {
+                        blocked_state(project_root, &name, tool)?
+                    } else {
+                        source_state(
+                            project_root,
+                            &name,
+                            &source_path,
+                            &rule,
+                            tool,
+                            limits,
+                            &mut budget,
+                        )?
+                    };
+                    status.tools.insert(tool.name().to_string(), state);
+                }
+            }
+            Err(e) => status.error = Some(e.to_string()),
+        }
+
+        rules.insert(name, status);
+    }
+
+    // Tool files with no source rule
+    for &tool in enabled_tools {
+        let processor = get_processor(tool);
+        for tool_path in processor.discover_rules(project_root)? {
+            let Some(name) = extract_rule_name(&tool_path) else {
+                continue;
+            };
+            // Parts of a split rule belong to that rule, unless a source rule has the name
+            let is_source = rules.get(&name).is_some_and(|status| status.has_source);
+            let name = read_rule_file(&tool_path)
+                .ok()
+                .filter(|_| !is_source)
+                .and_then(|content| part_of(&name, &content))
+                .map_or(name, |(group, _)| group);
+
+            let status = rules.entry(name.clone()).or_insert_with(|| RuleStatus {
+                name,
+                has_source: false,
+                tools: enabled_tools
+                    .iter()
+                    .map(|t| (t.name().to_string(), RuleState::NotTargeted))
+                    .collect(),
+                error: None,
+            });
+
+            if !status.has_source {
+                status
+                    .tools
+                    .insert(tool.name().to_string(), RuleState::Unmanaged);
+            }
+        }
+    }
+
+    Ok(StatusReport {
+        tools: enabled_tools.iter().map(|t| t.name().to_string()).collect(),
+        rules: rules.into_values().collect(),
+        budget,
+    })
+}
+
+/// Whether `policy` keeps a rule that targets `tool` out of it, as in `sync`
+fn is_blocked(rule: &AgentSyncRule, tool: Tool, policy: FidelityPolicy) -> bool {
+    rule.targets.iter().any(|t| t == "*" || t == tool.name())
+        && policy != FidelityPolicy::Approximate
+        && !fidelity_warnings(rule, tool).is_empty()
+}
+
+/// State of a rule kept out of `tool`: files left from an earlier sync are
+/// removed by the next one
+fn blocked_state(project_root: &Path, rule_name: &str, tool: Tool) -> Result<RuleState> {
+    let tool_path = get_processor(tool).rule_path(project_root, rule_name)?;
+    let exists = tool_path.exists() || !existing_parts(project_root, tool, rule_name)?.is_empty();
+    Ok(if exists {
+        RuleState::SourceAhead
+    } else {
+        RuleState::Skipped
+    })
+}
+
+/// State of a source rule in one tool, using the same comparison as `sync`
+fn source_state(
+    project_root: &Path,
+    rule_name: &str,
+    source_path: &Path,
+    rule: &Rule<AgentSyncRule>,
+    tool: Tool,
+    limits: &BTreeMap<String, ToolLimits>,
+    budget: &mut BudgetReport,
+) -> Result<RuleState> {
+    let targets = &rule.frontmatter.targets;
+    let targeted = targets.iter().any(|t| t == "*" || t == tool.name());
+
+    if !targeted {
+        let tool_path = get_processor(tool).rule_path(project_root, rule_name)?;
+        let exists =
+            tool_path.exists() || !existing_parts(project_root, tool, rule_name)?.is_empty();
+        return Ok(if exists {
+            RuleState::Orphaned
+        } else {
+            RuleState::NotTargeted
+        });
+    }
+
+    let limits = resolve_limits(limits, tool.name());
+    let always_on = converted_activation(&rule.frontmatter, tool)
+        .is_some_and(|(activation, _)| activation == Activation::Always);
+    let outputs = rule_outputs(project_root, rule_name, rule, tool, &limits)?.files;
+
+    let mut states = Vec::new();
+    for output in &outputs {
+        budget.record(
+            tool.name(),
+            &limits,
+            &output.name,
+            &output.content,
+            always_on,
+        );
+        states.push(match compare_file(&output.path, &output.content)? {
+            FileComparison::Missing => RuleState::Missing,
+            FileComparison::Identical => RuleState::InSync,
+            FileComparison::Different => {
+                // No sync state is recorded, so the most recently edited side wins
+                if modified_time(&output.path) > modified_time(source_path) {
+                    RuleState::ToolModified
+                } else {
+                    RuleState::SourceAhead
+                }
+            }
+        });
+    }
+    // Files left from an earlier split are removed by the next sync
+    if !stale_files(project_root, rule_name, tool, &outputs)?.is_empty() {
+        states.push(RuleState::SourceAhead);
+    }
+
+    // A split rule is as far out of sync as its furthest part, and tool edits come first
+    Ok(states
+        .into_iter()
+        .max_by_key(|state| match state {
+            RuleState::ToolModified => 3,
+            RuleState::SourceAhead => 2,
+            RuleState::Missing => 1,
+            _ => 0,
+        })
+        .unwrap_or(RuleState::InSync))
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs_err::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::project_with_rules;
+    use fs_err as fs;
+    use std::time::Duration;
+
+    const RULE: &str =
+        "---\ntargets: [\"cursor\"]\ndescription: Test\nglobs: \"**/*.rs\"\n---\n\n# Test\n";
+
+    fn state(report: &StatusReport, rule: &str, tool: &str) -> RuleState {
+        report
+            .rules
+            .iter()
+            .find(|r| r.name == rule)
+            .and_then(|r| r.tools.get(tool).copied())
+            .expect("rule should have a state for the tool")
+    }
+
+    fn set_mtime(path: &Path, time: SystemTime) {
+        fs::OpenOptions::new()
+            .write(true)
+            .open(path)
+            .expect("test operation should succeed")
+            .file()
+            .set_modified(time)
+            .expect("test operation should succeed");
+    }
+
+    #[test]
+    fn test_missing_and_not_targeted() {
+        let temp_dir = project_with_rules(&[("test", RULE)]);
+
+        let report = project_status(
+            temp_dir.path(),
+            &[Tool::Cursor, Tool::Windsurf],
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+        )
+        .expect("should compute status");
+
+        assert_eq!(state(&report, "test", "cursor"), RuleState::Missing);
+        assert_eq!(state(&report, "test", "windsurf"), RuleState::NotTargeted);
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn test_in_sync_source_ahead_and_tool_modified() {
+        let temp_dir = project_with_rules(&[("test", RULE)]);
+        let root = temp_dir.path();
+        let options = crate::sync::SyncOptions::default();
+        crate::sync::sync_to_tools(root, &["cursor".to_string()], &options)
+            .expect("sync should succeed");
+
+        let report = project_status(root, &[Tool::Cursor], &BTreeMap::new(), &BTreeMap::new())
+            .expect("should compute status");
+        assert_eq!(state(&report, "test", "cursor"), RuleState::InSync);
+        assert!(report.is_clean());
+
+        let source = root.join(".agentsync/rules/test.md");
+        let tool_file = root.join(".cursor/rules/test.mdc");
+        let earlier = SystemTime::now() - Duration::from_secs(60);
+
+        // Edit the tool file after the source
+        fs::write(&tool_file, "---\ndescription: Edited\n---\n")
+            .expect("test operation should succeed");
+        set_mtime(&source, earlier);
+        let report = project_status(root, &[Tool::Cursor], &BTreeMap::new(), &BTreeMap::new())
+            .expect("should compute status");
+        assert_eq!(state(&report, "test", "cursor"), RuleState::ToolModified);
+
+        // Edit the source after the tool file
+        fs::write(&source, RULE.replace("# Test", "# Changed"))
+            .expect("test operation should succeed");
+        set_mtime(&tool_file, earlier);
+        let report = project_status(root, &[Tool::Cursor], &BTreeMap::new(), &BTreeMap::new())
+            .expect("should compute status");
+        assert_eq!(state(&report, "test", "cursor"), RuleState::SourceAhead);
+    }
+
+    #[test]
+    fn test_orphaned_and_unmanaged() {
+        let temp_dir = project_with_rules(&[("test", RULE)]);
+        let root = temp_dir.path();
+        let windsurf_dir = root.join(".windsurf/rules");
+        fs::create_dir_all(&windsurf_dir).expect("test operation should succeed");
+        fs::write(
+            windsurf_dir.join("test.md"),
+            "---\ntrigger: always_on\n---\n",
+        )
+        .expect("test operation should succeed");
+        fs::write(
+            windsurf_dir.join("legacy.md"),
+            "---\ntrigger: always_on\n---\n",
+        )
+        .expect("test operation should succeed");
+
+        let report = project_status(
+            root,
+            &[Tool::Cursor, Tool::Windsurf],
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+        )
+        .expect("should compute status");
+
+        assert_eq!(state(&report, "test", "windsurf"), RuleState::Orphaned);
+        assert_eq!(state(&report, "legacy", "windsurf"), RuleState::Unmanaged);
+        assert_eq!(state(&report, "legacy", "cursor"), RuleState::NotTargeted);
+    }
+
+    #[test]
+    fn test_budget_uses_configured_limits() {
+        let temp_dir = project_with_rules(&[(
+            "test",
+            "---\ntargets: [\"*\"]\nactivation: always\n---\n\nAlways check errors.\n",
+        )]);
+        let limits = [(
+            "cursor".to_string(),
+            ToolLimits {
+                max_always_on_tokens: Some(10),
+                ..ToolLimits::default()
+            },
+        )]
+        .into();
+
+        let report = project_status(
+            temp_dir.path(),
+            &[Tool::Cursor, Tool::Windsurf],
+            &limits,
+            &BTreeMap::new(),
+        )
+        .expect("should compute status");
+
+        let budgets = &report.budget.tools;
+        assert_eq!(budgets.len(), 2);
+        assert_eq!(budgets[0].always_on().count(), 1);
+        assert_eq!(budgets[1].limits.max_file_chars, Some(6000));
+        let violations = report.budget.violations();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].tool, "cursor");
+        // Limits are reported, not part of the sync state
+        assert_eq!(state(&report, "test", "cursor"), RuleState::Missing);
+    }
+
+    #[test]
+    fn test_json_uses_snake_case_states() {
+        let temp_dir = project_with_rules(&[("test", RULE)]);
+
+        let report = project_status(
+            temp_dir.path(),
+            &[Tool::Cursor],
+            &BTreeMap::new(),
+            &BTreeMap::new(),
+        )
+        .expect("should compute status");
+        let json = serde_json::to_string(&report).expect("should serialize");
+
+        assert!(json.contains(r#""tools":{"cursor":"missing"}"#), "{json}");
+        assert!(!json.contains("error"));
+    }
+}
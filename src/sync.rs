@@ -1,20 +1,44 @@
 //! Bidirectional sync engine for AgentSync ↔ tool formats.
 
+use crate::budget::BudgetReport;
+use crate::converter::{Activation, FidelityWarning, converted_activation, fidelity_warnings};
 use crate::fs::{
     Tool, discover_rules, extract_rule_name, read_rule_file, rule_path, write_rule_file,
 };
-use crate::models::AgentSyncRule;
-use crate::parser::{parse_frontmatter, serialize_frontmatter};
+use crate::models::{AgentSyncRule, FidelityPolicy, LimitPolicy, ToolLimits, resolve_limits};
+use crate::parser::{
+    FrontmatterIssue, check_frontmatter, parse_frontmatter, serialize_frontmatter,
+};
 use crate::processor::get_processor;
+use crate::split::{RuleOutput, RuleOutputs, join_parts, part_of, rule_outputs, stale_files};
 use crate::{AgentSyncError, Result};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use tracing::{debug, info};
 
 /// Options for sync operations
 #[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)] // Independent CLI flags, not a state machine
 pub struct SyncOptions {
     pub dry_run: bool,
     pub verbose: bool,
+    /// Treat frontmatter warnings (unknown keys, invalid values) as errors
+    pub strict: bool,
+    /// When importing, leave rules that already exist in `.agentsync/rules/` untouched
+    pub keep_existing: bool,
+    /// Per-tool policy for lossy conversions, from `agentsync.json`; unlisted tools approximate
+    pub fidelity: BTreeMap<String, FidelityPolicy>,
+    /// Per-tool size limits, from `agentsync.json`; unlisted tools use the built-in limits
+    pub limits: BTreeMap<String, ToolLimits>,
+}
+
+/// A rule a tool can't represent faithfully, and what its policy did about it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LossyConversion {
+    /// "rule (tool)"
+    pub rule: String,
+    pub warning: FidelityWarning,
+    pub policy: FidelityPolicy,
 }
 
 /// Result of a sync operation
@@ -23,8 +47,18 @@ pub struct SyncResult {
     pub added: Vec<String>,
     pub updated: Vec<String>,
     pub skipped: Vec<String>,
+    /// Rules a fidelity or size policy kept out of a tool
+    pub withheld: Vec<String>,
+    /// Tool files deleted because a rule's split changed
+    pub removed: Vec<String>,
     /// (rule name, error message)
     pub errors: Vec<(String, String)>,
+    /// (rule name, warning message)
+    pub warnings: Vec<(String, String)>,
+    /// Conversions that lost meaning, whether written, skipped or refused
+    pub lossy: Vec<LossyConversion>,
+    /// Size of every rule written or up to date, per tool
+    pub budget: BudgetReport,
 }
 
 impl SyncResult {
@@ -37,13 +71,25 @@ impl SyncResult {
     }
 
     pub fn has_changes(&self) -> bool {
-        !self.added.is_empty() || !self.updated.is_empty()
+        !self.added.is_empty() || !self.updated.is_empty() || !self.removed.is_empty()
     }
 
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
     }
 
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// Lossy conversions refused by a `fail` fidelity policy
+    pub fn fidelity_failures(&self) -> usize {
+        self.lossy
+            .iter()
+            .filter(|lossy| lossy.policy == FidelityPolicy::Fail)
+            .count()
+    }
+
     /// Print sync summary
     #[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
     pub fn print_summary(&self, dry_run: bool) {
@@ -63,6 +109,13 @@ impl SyncResult {
                     println!("  ~ {rule}");
                 }
             }
+
+            if !self.removed.is_empty() {
+                println!("\n{}✓ Removed {} file(s):", prefix, self.removed.len());
+                for rule in &self.removed {
+                    println!("  - {rule}");
+                }
+            }
         }
 
         if !self.skipped.is_empty() {
@@ -73,6 +126,36 @@ impl SyncResult {
             );
         }
 
+        if !self.withheld.is_empty() {
+            println!(
+                "\n{}→ Not written {} rule(s) (kept out by a fidelity or size policy):",
+                prefix,
+                self.withheld.len()
+            );
+            for rule in &self.withheld {
+                println!("  - {rule}");
+            }
+        }
+
+        if self.has_warnings() {
+            println!("\n{}⚠ Warnings in {} rule(s):", prefix, self.warnings.len());
+            for (rule, warning) in &self.warnings {
+                println!("  ? {rule}: {warning}");
+            }
+        }
+
+        if !self.lossy.is_empty() {
+            println!("\n{}⚠ Lossy conversions ({}):", prefix, self.lossy.len());
+            for lossy in &self.lossy {
+                let action = match lossy.policy {
+                    FidelityPolicy::Approximate => "",
+                    FidelityPolicy::Skip => " (skipped)",
+                    FidelityPolicy::Fail => " (failed)",
+                };
+                println!("  ~ {}: {}{action}", lossy.rule, lossy.warning);
+            }
+        }
+
         if self.has_errors() {
             println!("\n{}✗ Errors in {} rule(s):", prefix, self.errors.len());
             for (rule, error) in &self.errors {
@@ -80,10 +163,12 @@ impl SyncResult {
             }
         }
 
-        if !self.has_changes() && !self.has_errors() {
+        if !self.has_changes() && !self.has_errors() && self.withheld.is_empty() {
             println!("{prefix}✓ All rules are up-to-date");
         }
 
+        self.budget.print(prefix);
+
         if dry_run && self.has_changes() {
             println!("\nNo files were modified (dry-run mode)");
         }
@@ -97,6 +182,44 @@ pub fn sync_to_tools(
     options: &SyncOptions,
 ) -> Result<SyncResult> {
     info!("Starting sync from AgentSync to tools");
+
+    // Totals are only known once every rule is converted, so tools that fail on
+    // them are measured in a dry run first and then left untouched
+    let fails_on_totals = enabled_tools.iter().any(|tool| {
+        let limits = resolve_limits(&options.limits, tool);
+        limits.on_exceed == LimitPolicy::Fail
+            && (limits.max_total_chars.is_some() || limits.max_always_on_tokens.is_some())
+    });
+    let held = if fails_on_totals {
+        let measure = SyncOptions {
+            dry_run: true,
+            verbose: false,
+            ..options.clone()
+        };
+        sync_rules_to_tools(project_root, enabled_tools, &measure, &[])?
+            .budget
+            .failing_totals()
+    } else {
+        Vec::new()
+    };
+
+    let mut result = sync_rules_to_tools(project_root, enabled_tools, options, &held)?;
+    for tool_name in held {
+        result.warnings.push((
+            tool_name,
+            "not synced: its rules exceed a total size limit under `onExceed: fail`".to_string(),
+        ));
+    }
+    Ok(result)
+}
+
+/// Sync every rule to the enabled tools, without writing to the `held` ones
+fn sync_rules_to_tools(
+    project_root: &Path,
+    enabled_tools: &[String],
+    options: &SyncOptions,
+    held: &[String],
+) -> Result<SyncResult> {
     let mut result = SyncResult::new();
 
     let agentsync_rules = discover_rules(project_root, Tool::AgentSync)?;
@@ -128,10 +251,20 @@ pub fn sync_to_tools(
             }
         };
 
-        let agentsync_rule = match parse_frontmatter::<AgentSyncRule>(
-            &content,
-            Some(&rule_path.display().to_string()),
-        ) {
+        let path_str = rule_path.display().to_string();
+        match check_frontmatter::<AgentSyncRule>(&content, Some(&path_str)) {
+            Ok(issues) => {
+                if record_frontmatter_issues(&rule_name, issues, options, &mut result) {
+                    continue;
+                }
+            }
+            Err(e) => {
+                result.errors.push((rule_name.clone(), e.to_string()));
+                continue;
+            }
+        }
+
+        let agentsync_rule = match parse_frontmatter::<AgentSyncRule>(&content, Some(&path_str)) {
             Ok(r) => r,
             Err(e) => {
                 result.errors.push((rule_name.clone(), e.to_string()));
@@ -168,6 +301,7 @@ pub fn sync_to_tools(
                 &agentsync_rule,
                 tool,
                 options,
+                held.contains(tool_name),
                 &mut result,
             ) {
                 result
@@ -180,37 +314,180 @@ pub fn sync_to_tools(
     Ok(result)
 }
 
-/// Sync a single AgentSync rule to a tool
+/// Record frontmatter issues as warnings, or as errors under `--strict`.
+///
+/// Returns `true` if the rule must be skipped.
+fn record_frontmatter_issues(
+    rule_name: &str,
+    issues: Vec<FrontmatterIssue>,
+    options: &SyncOptions,
+    result: &mut SyncResult,
+) -> bool {
+    if issues.is_empty() {
+        return false;
+    }
+
+    if options.strict {
+        for issue in issues {
+            result
+                .errors
+                .push((rule_name.to_string(), issue.into_error().to_string()));
+        }
+        return true;
+    }
+
+    for issue in issues {
+        result
+            .warnings
+            .push((rule_name.to_string(), issue.to_string()));
+    }
+    false
+}
+
+/// How a file on disk compares to the content a sync would write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileComparison {
+    Missing,
+    Identical,
+    Different,
+}
+
+/// Compare an existing file against the content a sync would write
+pub(crate) fn compare_file(path: &Path, expected: &str) -> Result<FileComparison> {
+    if !path.exists() {
+        return Ok(FileComparison::Missing);
+    }
+
+    if read_rule_file(path)? == expected {
+        Ok(FileComparison::Identical)
+    } else {
+        Ok(FileComparison::Different)
+    }
+}
+
+/// Sync a single AgentSync rule to a tool; a `held` tool is only measured
 fn sync_rule_to_tool(
     project_root: &Path,
     rule_name: &str,
     agentsync_rule: &crate::models::Rule<AgentSyncRule>,
     tool: Tool,
     options: &SyncOptions,
+    held: bool,
     result: &mut SyncResult,
 ) -> Result<()> {
-    let processor = get_processor(tool);
-    let tool_path = processor.rule_path(project_root, rule_name)?;
     let tool_name = tool.name();
     let full_name = format!("{rule_name} ({tool_name})");
-    let tool_content = processor.convert_from_agentsync(agentsync_rule)?;
 
-    // Check if file exists and compare content
-    let is_new = !tool_path.exists();
-    let needs_update = if is_new {
-        true
-    } else {
-        let existing_content = read_rule_file(&tool_path)?;
-        existing_content != tool_content
-    };
+    let policy = options.fidelity.get(tool_name).copied().unwrap_or_default();
+    let warnings = fidelity_warnings(&agentsync_rule.frontmatter, tool);
+    let blocked = policy != FidelityPolicy::Approximate && !warnings.is_empty();
+    result
+        .lossy
+        .extend(warnings.into_iter().map(|warning| LossyConversion {
+            rule: full_name.clone(),
+            warning,
+            policy,
+        }));
+    if blocked {
+        debug!("Not writing {full_name}: fidelity policy is {policy}");
+        result.withheld.push(full_name);
+        if held {
+            return Ok(());
+        }
+        // A file written by an earlier, approximate sync would otherwise stay behind
+        let stale = stale_files(project_root, rule_name, tool, &[])?;
+        return remove_stale(tool, &stale, options, result);
+    }
+
+    let limits = resolve_limits(&options.limits, tool_name);
+    let always_on = converted_activation(&agentsync_rule.frontmatter, tool)
+        .is_some_and(|(activation, _)| activation == Activation::Always);
+    let RuleOutputs {
+        files: outputs,
+        clash,
+    } = rule_outputs(project_root, rule_name, agentsync_rule, tool, &limits)?;
+    if let Some(clash) = clash {
+        result.warnings.push((
+            full_name.clone(),
+            format!("not split: part `{clash}` would overwrite the rule of that name"),
+        ));
+    }
+
+    for output in &outputs {
+        let chars = result
+            .budget
+            .record(tool_name, &limits, &output.name, &output.content, always_on)
+            .chars;
+        if held {
+            result
+                .withheld
+                .push(format!("{} ({tool_name})", output.name));
+            continue;
+        }
+        if limits.on_exceed == LimitPolicy::Fail
+            && limits.max_file_chars.is_some_and(|max| chars > max)
+        {
+            debug!("Not writing {full_name}: {chars} characters is over the per-file limit");
+            result
+                .withheld
+                .push(format!("{} ({tool_name})", output.name));
+            continue;
+        }
+        write_output(tool, output, options, result)?;
+    }
+    if held {
+        debug!("Not writing {full_name}: {tool_name} rules are over a total limit");
+        return Ok(());
+    }
+
+    // Split parts replace the whole file, and a new split replaces the old parts
+    let stale = stale_files(project_root, rule_name, tool, &outputs)?;
+    remove_stale(tool, &stale, options, result)
+}
+
+/// Remove files of a rule that `sync` no longer writes
+fn remove_stale(
+    tool: Tool,
+    stale: &[PathBuf],
+    options: &SyncOptions,
+    result: &mut SyncResult,
+) -> Result<()> {
+    for path in stale {
+        if !options.dry_run {
+            fs_err::remove_file(path)?;
+        }
+        let full_name = format!(
+            "{} ({})",
+            extract_rule_name(path).unwrap_or_default(),
+            tool.name()
+        );
+        if options.verbose {
+            info!("Removed {full_name}");
+        }
+        result.removed.push(full_name);
+    }
+
+    Ok(())
+}
+
+/// Write one file of a rule to a tool, unless it is already up to date
+fn write_output(
+    tool: Tool,
+    output: &RuleOutput,
+    options: &SyncOptions,
+    result: &mut SyncResult,
+) -> Result<()> {
+    let full_name = format!("{} ({})", output.name, tool.name());
+    let comparison = compare_file(&output.path, &output.content)?;
+    let is_new = comparison == FileComparison::Missing;
 
-    if !needs_update {
+    if comparison == FileComparison::Identical {
         result.skipped.push(full_name);
         return Ok(());
     }
 
     if !options.dry_run {
-        processor.write_rule(&tool_path, &tool_content)?;
+        get_processor(tool).write_rule(&output.path, &output.content)?;
     }
 
     if is_new {
@@ -252,51 +529,51 @@ pub fn sync_from_tool(
         return Ok(result);
     }
 
-    // Process each tool rule
-    for tool_rule_path in tool_rules {
-        let Some(rule_name) = extract_rule_name(&tool_rule_path) else {
-            result.errors.push((
-                tool_rule_path.display().to_string(),
-                "Invalid rule name".to_string(),
-            ));
-            continue;
-        };
-
+    // Process each tool rule, with split rules joined back together
+    for (rule_name, tool_rule_path, content) in read_tool_rules(tool_rules, &mut result) {
         debug!("Processing rule: {rule_name}");
 
-        // Read and parse the tool rule
-        let content = match read_rule_file(&tool_rule_path) {
-            Ok(c) => c,
+        let path_str = tool_rule_path.display().to_string();
+        match processor.check_frontmatter(&content, &path_str) {
+            Ok(issues) => {
+                if record_frontmatter_issues(&rule_name, issues, options, &mut result) {
+                    continue;
+                }
+            }
             Err(e) => {
                 result.errors.push((rule_name.clone(), e.to_string()));
                 continue;
             }
-        };
+        }
 
         // Convert tool rule to AgentSync format
-        let agentsync_rule =
-            match processor.convert_to_agentsync(&content, &tool_rule_path.display().to_string()) {
-                Ok(rule) => rule,
-                Err(e) => {
-                    result.errors.push((rule_name.clone(), e.to_string()));
-                    continue;
-                }
-            };
+        let agentsync_rule = match processor.convert_to_agentsync(&content, &path_str) {
+            Ok(rule) => rule,
+            Err(e) => {
+                result.errors.push((rule_name.clone(), e.to_string()));
+                continue;
+            }
+        };
 
         // Write to AgentSync directory
         let agentsync_path = rule_path(project_root, Tool::AgentSync, &rule_name)?;
         let agentsync_content = serialize_frontmatter(&agentsync_rule)?;
 
-        // Check if file exists and compare content
-        let is_new = !agentsync_path.exists();
-        let needs_update = if is_new {
-            true
-        } else {
-            let existing_content = read_rule_file(&agentsync_path)?;
-            existing_content != agentsync_content
-        };
+        let comparison = compare_file(&agentsync_path, &agentsync_content)?;
+        let is_new = comparison == FileComparison::Missing;
+
+        if options.keep_existing && comparison == FileComparison::Different {
+            result.warnings.push((
+                rule_name.clone(),
+                format!(
+                    "already exists in .agentsync/rules/, kept it instead of the {tool} version"
+                ),
+            ));
+            result.skipped.push(rule_name.clone());
+            continue;
+        }
 
-        if !needs_update {
+        if comparison == FileComparison::Identical {
             result.skipped.push(rule_name.clone());
             continue;
         }
@@ -321,6 +598,91 @@ pub fn sync_from_tool(
     Ok(result)
 }
 
+/// Read a tool's rule files, joining the parts of each split rule into one
+/// rule named after it. Unreadable files and incomplete splits are errors.
+fn read_tool_rules(paths: Vec<PathBuf>, result: &mut SyncResult) -> Vec<(String, PathBuf, String)> {
+    let mut rules = Vec::new();
+    let mut groups: BTreeMap<String, (PathBuf, Vec<String>)> = BTreeMap::new();
+
+    for path in paths {
+        let Some(name) = extract_rule_name(&path) else {
+            result
+                .errors
+                .push((path.display().to_string(), "Invalid rule name".to_string()));
+            continue;
+        };
+
+        let content = match read_rule_file(&path) {
+            Ok(c) => c,
+            Err(e) => {
+                result.errors.push((name, e.to_string()));
+                continue;
+            }
+        };
+
+        match part_of(&name, &content) {
+            Some((group, _)) => groups
+                .entry(group)
+                .or_insert_with(|| (path, Vec::new()))
+                .1
+                .push(content),
+            None => rules.push((name, path, content)),
+        }
+    }
+
+    for (name, (path, parts)) in groups {
+        match join_parts(&parts) {
+            Some(content) => rules.push((name, path, content)),
+            None => result.errors.push((
+                name,
+                format!(
+                    "split into parts, but only {} of them were found",
+                    parts.len()
+                ),
+            )),
+        }
+    }
+
+    rules
+}
+
+/// Import rules from several tools into `.agentsync/rules/`, merging them.
+///
+/// Tools are imported in order; when two tools have a rule with the same name,
+/// the first one imported wins and the others are reported as warnings.
+pub fn import_from_tools(
+    project_root: &Path,
+    tools: &[Tool],
+    options: &SyncOptions,
+) -> Result<SyncResult> {
+    let options = SyncOptions {
+        keep_existing: true,
+        ..options.clone()
+    };
+    let mut merged = SyncResult::new();
+
+    for &tool in tools {
+        let result = sync_from_tool(project_root, tool, &options)?;
+        let label = |rule: String| format!("{rule} ({tool})");
+
+        merged.added.extend(result.added.into_iter().map(label));
+        merged.updated.extend(result.updated.into_iter().map(label));
+        merged.skipped.extend(result.skipped.into_iter().map(label));
+        merged
+            .errors
+            .extend(result.errors.into_iter().map(|(rule, e)| (label(rule), e)));
+        merged.warnings.extend(
+            result
+                .warnings
+                .into_iter()
+                .map(|(rule, w)| (label(rule), w)),
+        );
+        merged.lossy.extend(result.lossy);
+    }
+
+    Ok(merged)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +692,8 @@ mod tests {
         let options = SyncOptions::default();
         assert!(!options.dry_run);
         assert!(!options.verbose);
+        assert!(!options.strict);
+        assert!(!options.keep_existing);
     }
 
     #[test]
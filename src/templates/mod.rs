@@ -0,0 +1,222 @@
+//! Starter rules for `agentsync add --template` and `agentsync templates`.
+//!
+//! Built-in templates are embedded in the binary. A project can add its own, or
+//! replace a built-in one, by putting rule files in `.agentsync/templates/`.
+
+use crate::converter::{Activation, activation_globs, intended_activation};
+use crate::fs::{Tool, extract_rule_name, read_rule_file};
+use crate::models::{AgentSyncRule, Rule};
+use crate::parser::parse_frontmatter;
+use crate::{AgentSyncError, Result};
+use serde::Serialize;
+use std::path::Path;
+use strum_macros::Display;
+
+/// Directory, relative to the project root, holding project templates
+pub const TEMPLATES_DIR: &str = ".agentsync/templates";
+
+const BUILTIN: [(&str, &str); 6] = [
+    ("commit-messages", include_str!("commit-messages.md")),
+    ("python", include_str!("python.md")),
+    ("rust", include_str!("rust.md")),
+    ("security-review", include_str!("security-review.md")),
+    ("testing", include_str!("testing.md")),
+    ("typescript", include_str!("typescript.md")),
+];
+
+/// Where a template comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TemplateSource {
+    /// Embedded in the binary
+    BuiltIn,
+    /// A file in `.agentsync/templates/`
+    Project,
+}
+
+/// A starter rule: an `AgentSync` rule file used as defaults for `agentsync add`
+#[derive(Debug, Clone)]
+pub struct Template {
+    pub name: String,
+    pub source: TemplateSource,
+    /// Raw rule file, frontmatter included
+    pub content: String,
+}
+
+/// What `agentsync templates` shows about one template
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateSummary {
+    pub name: String,
+    pub source: TemplateSource,
+    pub description: String,
+    /// Activation a rule created from the template gets
+    pub activation: Activation,
+    /// Globs for glob activation, empty otherwise
+    pub globs: String,
+}
+
+impl Template {
+    /// Parse the template as an `AgentSync` rule
+    pub fn parse(&self) -> Result<Rule<AgentSyncRule>> {
+        parse_frontmatter(&self.content, Some(&format!("template '{}'", self.name)))
+    }
+
+    /// Name, source, description and activation, for listing
+    pub fn summary(&self) -> Result<TemplateSummary> {
+        let rule = self.parse()?.frontmatter;
+        let (activation, globs) = activation_and_globs(&rule);
+
+        Ok(TemplateSummary {
+            name: self.name.clone(),
+            source: self.source,
+            description: rule.description,
+            activation,
+            globs,
+        })
+    }
+}
+
+/// Activation and globs a rule created from `template` gets
+#[must_use]
+pub fn activation_and_globs(template: &AgentSyncRule) -> (Activation, String) {
+    let activation = intended_activation(template);
+    let globs = if activation.uses_globs() {
+        activation_globs(
+            template,
+            Tool::AgentSync,
+            template.windsurf.as_ref().map(|w| w.globs.as_str()),
+        )
+    } else {
+        String::new()
+    };
+    (activation, globs)
+}
+
+/// Templates shipped with agentsync, sorted by name
+#[must_use]
+pub fn builtin_templates() -> Vec<Template> {
+    BUILTIN
+        .iter()
+        .map(|(name, content)| Template {
+            name: (*name).to_string(),
+            source: TemplateSource::BuiltIn,
+            content: (*content).to_string(),
+        })
+        .collect()
+}
+
+/// Templates in the project's `.agentsync/templates/` directory
+pub fn project_templates(project_root: &Path) -> Result<Vec<Template>> {
+    let dir = project_root.join(TEMPLATES_DIR);
+    crate::security::validate_path_within_base(project_root, &dir)?;
+
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let pattern = format!("{}/*.md", dir.display());
+    let mut templates = Vec::new();
+    for entry in glob::glob(&pattern)? {
+        let path = entry.map_err(|e| AgentSyncError::Io(e.into_error()))?;
+        let Some(name) = extract_rule_name(&path) else {
+            continue;
+        };
+        templates.push(Template {
+            name,
+            source: TemplateSource::Project,
+            content: read_rule_file(&path)?,
+        });
+    }
+
+    Ok(templates)
+}
+
+/// Every available template, sorted by name.
+///
+/// Project templates replace built-in ones with the same name.
+pub fn list_templates(project_root: Option<&Path>) -> Result<Vec<Template>> {
+    let project = match project_root {
+        Some(root) => project_templates(root)?,
+        None => Vec::new(),
+    };
+
+    let mut templates: Vec<Template> = builtin_templates()
+        .into_iter()
+        .filter(|builtin| !project.iter().any(|own| own.name == builtin.name))
+        .collect();
+    templates.extend(project);
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(templates)
+}
+
+/// Look up a template by name, preferring the project's own
+pub fn find_template(project_root: Option<&Path>, name: &str) -> Result<Template> {
+    list_templates(project_root)?
+        .into_iter()
+        .find(|template| template.name == name)
+        .ok_or_else(|| AgentSyncError::TemplateNotFound {
+            name: name.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fs_err as fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_builtin_templates_parse() {
+        let templates = builtin_templates();
+        assert_eq!(templates.len(), BUILTIN.len());
+
+        for template in &templates {
+            let rule = template
+                .parse()
+                .unwrap_or_else(|e| panic!("template {} should parse: {e}", template.name));
+            assert!(!rule.frontmatter.description.is_empty());
+            assert!(rule.content.contains("# "));
+        }
+
+        let rust = find_template(None, "rust")
+            .and_then(|template| template.summary())
+            .expect("should summarize");
+        assert_eq!(rust.activation, Activation::Glob);
+        assert_eq!(rust.globs, "**/*.rs");
+    }
+
+    #[test]
+    fn test_project_templates_override_builtin() {
+        let temp_dir = TempDir::new().expect("should create temp dir");
+        let dir = temp_dir.path().join(TEMPLATES_DIR);
+        fs::create_dir_all(&dir).expect("test operation should succeed");
+        fs::write(
+            dir.join("rust.md"),
+            "---\ndescription: Our Rust\n---\n# Ours\n",
+        )
+        .expect("test operation should succeed");
+        fs::write(dir.join("go.md"), "---\ndescription: Go\n---\n# Go\n")
+            .expect("test operation should succeed");
+
+        let templates = list_templates(Some(temp_dir.path())).expect("should list templates");
+        assert_eq!(templates.len(), BUILTIN.len() + 1);
+
+        let rust = find_template(Some(temp_dir.path()), "rust").expect("should find rust");
+        assert_eq!(rust.source, TemplateSource::Project);
+        assert!(rust.content.contains("Our Rust"));
+
+        let go = find_template(Some(temp_dir.path()), "go").expect("should find go");
+        assert_eq!(go.source, TemplateSource::Project);
+    }
+
+    #[test]
+    fn test_find_template_not_found() {
+        let result = find_template(None, "cobol");
+        assert!(matches!(
+            result,
+            Err(AgentSyncError::TemplateNotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,16 @@
+//! Helpers shared by the unit tests
+
+use fs_err as fs;
+use tempfile::TempDir;
+
+/// A temporary project with the given `(name, content)` rules in `.agentsync/rules/`
+pub(crate) fn project_with_rules(rules: &[(&str, &str)]) -> TempDir {
+    let temp_dir = TempDir::new().expect("should create temp dir");
+    let rules_dir = temp_dir.path().join(".agentsync/rules");
+    fs::create_dir_all(&rules_dir).expect("test operation should succeed");
+    for (name, content) in rules {
+        fs::write(rules_dir.join(format!("{name}.md")), content)
+            .expect("test operation should succeed");
+    }
+    temp_dir
+}
@@ -0,0 +1,621 @@
+//! Validate `agentsync.json` and rule files, collecting every problem instead of stopping at the first.
+
+use crate::converter::converted_activation;
+use crate::fs::{
+    Tool, discover_rules, display_relative, extract_rule_name, project_files, read_rule_file,
+};
+use crate::globs::{Dialect, GlobMatcher, split_patterns};
+use crate::models::{AgentSyncConfig, AgentSyncRule, WindsurfTrigger};
+use crate::parser::{FrontmatterMap, check_frontmatter, parse_frontmatter, parse_frontmatter_map};
+use crate::processor::get_processor;
+use crate::{AgentSyncError, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// How serious a validation problem is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    /// Only fails validation under `--strict`
+    Warning,
+}
+
+/// A single validation problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub severity: Severity,
+    /// File path, optionally followed by `:line:column`
+    pub location: String,
+    pub message: String,
+}
+
+/// Result of validating a project or a tool's rules
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub problems: Vec<Problem>,
+    /// Number of rule files checked
+    pub rules_checked: usize,
+}
+
+impl ValidationReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.problems
+            .iter()
+            .filter(|p| p.severity == Severity::Error)
+            .count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.problems
+            .iter()
+            .filter(|p| p.severity == Severity::Warning)
+            .count()
+    }
+
+    /// Whether validation passed; warnings only fail under `strict`
+    pub fn is_valid(&self, strict: bool) -> bool {
+        self.error_count() == 0 && (!strict || self.warning_count() == 0)
+    }
+
+    /// Add another report's problems to this one
+    pub fn extend(&mut self, other: Self) {
+        self.problems.extend(other.problems);
+    }
+
+    fn error(&mut self, location: impl Into<String>, message: impl Into<String>) {
+        self.problems.push(Problem {
+            severity: Severity::Error,
+            location: location.into(),
+            message: message.into(),
+        });
+    }
+
+    fn warning(&mut self, location: impl Into<String>, message: impl Into<String>) {
+        self.problems.push(Problem {
+            severity: Severity::Warning,
+            location: location.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Print every problem followed by a summary line
+    #[allow(clippy::print_stdout)] // This is user-facing output, not debug logging
+    pub fn print(&self) {
+        for problem in &self.problems {
+            let label = match problem.severity {
+                Severity::Error => "✗ error",
+                Severity::Warning => "⚠ warning",
+            };
+            println!(
+                "{label}: {}: {}",
+                problem.location,
+                problem.message.replace('\n', "\n    ")
+            );
+        }
+
+        if self.problems.is_empty() {
+            println!(
+                "✓ Configuration and {} rule(s) are valid",
+                self.rules_checked
+            );
+        } else {
+            println!(
+                "\nFound {} error(s) and {} warning(s) in {} rule(s)",
+                self.error_count(),
+                self.warning_count(),
+                self.rules_checked
+            );
+        }
+    }
+}
+
+/// Message of an error without the generic hints appended by its `Display`
+fn error_message(err: AgentSyncError) -> String {
+    match err {
+        AgentSyncError::ConfigError { error } => error,
+        AgentSyncError::FrontmatterParse { message, .. } => message,
+        other => other.to_string(),
+    }
+}
+
+/// Location of a frontmatter error, falling back to the file itself
+fn error_location(file: &str, err: &AgentSyncError) -> String {
+    match err {
+        AgentSyncError::FrontmatterParse {
+            line: Some(line),
+            column: Some(column),
+            ..
+        } => format!("{file}:{line}:{column}"),
+        AgentSyncError::FrontmatterParse {
+            line: Some(line), ..
+        } => format!("{file}:{line}"),
+        _ => file.to_string(),
+    }
+}
+
+/// Validate `agentsync.json` and every rule in `.agentsync/rules/`
+pub fn validate_project(project_root: &Path, config_path: &Path) -> Result<ValidationReport> {
+    let mut report = ValidationReport::new();
+    let config = validate_config(project_root, config_path, &mut report);
+
+    // Without a readable config, still check rules against every tool
+    let enabled_tools = config.map_or_else(|| AgentSyncConfig::default().tools, |c| c.tools);
+
+    for rule_path in discover_rules(project_root, Tool::AgentSync)? {
+        report.rules_checked += 1;
+        validate_source_rule(project_root, &rule_path, &enabled_tools, &mut report);
+    }
+
+    Ok(report)
+}
+
+/// Validate the rules in a tool's directory (e.g. `.cursor/rules/`)
+pub fn validate_tool_rules(project_root: &Path, tool: Tool) -> Result<ValidationReport> {
+    if tool == Tool::AgentSync {
+        return Err(AgentSyncError::Other(
+            "Use `agentsync validate` without --tool to validate .agentsync/rules/".to_string(),
+        ));
+    }
+
+    let mut report = ValidationReport::new();
+    let processor = get_processor(tool);
+
+    for rule_path in processor.discover_rules(project_root)? {
+        report.rules_checked += 1;
+        let file = display_relative(project_root, &rule_path);
+
+        let content = match read_rule_file(&rule_path) {
+            Ok(c) => c,
+            Err(e) => {
+                report.error(&file, e.to_string());
+                continue;
+            }
+        };
+
+        match processor.check_frontmatter(&content, &file) {
+            Ok(issues) => {
+                for issue in issues {
+                    report.warning(
+                        format!("{file}:{}:{}", issue.span.line, issue.span.column),
+                        issue.message,
+                    );
+                }
+            }
+            Err(e) => {
+                report.error(error_location(&file, &e), error_message(e));
+                continue;
+            }
+        }
+
+        if let Err(e) = processor.convert_to_agentsync(&content, &file) {
+            report.error(error_location(&file, &e), error_message(e));
+        }
+    }
+
+    Ok(report)
+}
+
+/// Check each rule's globs against the files in the working tree.
+///
+/// Warns about patterns that match no file in a tool that attaches the rule by
+/// globs, and about rules that match exactly the same files as another rule.
+/// A rule matching only some of another rule's files is not flagged, since a
+/// narrower rule usually refines a broader one (`**/*.rs` and `tests/**/*.rs`).
+/// Rules that fail to parse are skipped; `validate_project` reports them.
+pub fn validate_glob_coverage(
+    project_root: &Path,
+    enabled_tools: &[String],
+) -> Result<ValidationReport> {
+    let mut report = ValidationReport::new();
+    let files = project_files(project_root)?;
+    let tools: Vec<Tool> = enabled_tools
+        .iter()
+        .filter_map(|name| name.parse().ok())
+        .collect();
+
+    // Per tool: files matched → rules matching exactly those files
+    let mut match_sets: Vec<BTreeMap<BTreeSet<&str>, Vec<String>>> =
+        vec![BTreeMap::new(); tools.len()];
+
+    for rule_path in discover_rules(project_root, Tool::AgentSync)? {
+        let file = display_relative(project_root, rule_path.as_path());
+        let Some(name) = extract_rule_name(&rule_path) else {
+            continue;
+        };
+        let Ok(content) = read_rule_file(&rule_path) else {
+            continue;
+        };
+        let (Ok(map), Ok(rule)) = (
+            parse_frontmatter_map(&content, Some(&file)),
+            parse_frontmatter::<AgentSyncRule>(&content, Some(&file)),
+        ) else {
+            continue;
+        };
+        let rule = rule.frontmatter;
+
+        // pattern → tools in which it matches nothing
+        let mut dead: BTreeMap<String, Vec<Tool>> = BTreeMap::new();
+        let mut locations: BTreeMap<String, String> = BTreeMap::new();
+
+        for (index, &tool) in tools.iter().enumerate() {
+            if !rule.targets.iter().any(|t| t == "*" || t == tool.name()) {
+                continue;
+            }
+            let Some((activation, globs)) = converted_activation(&rule, tool) else {
+                continue;
+            };
+            if !activation.uses_globs() || globs.is_empty() {
+                continue;
+            }
+
+            let dialect = Dialect::of(tool);
+            for pattern in split_patterns(&globs)
+                .into_iter()
+                .filter(|p| !p.starts_with('!'))
+            {
+                let matcher = GlobMatcher::new(pattern, dialect);
+                if !matcher.is_empty() && !files.iter().any(|f| matcher.matches(f)) {
+                    let key = match_key(&map, pattern);
+                    locations
+                        .entry(pattern.to_string())
+                        .or_insert_with(|| key_location(&file, &map, key));
+                    dead.entry(pattern.to_string()).or_default().push(tool);
+                }
+            }
+
+            let matcher = GlobMatcher::new(&globs, dialect);
+            let selected: BTreeSet<&str> = files
+                .iter()
+                .map(String::as_str)
+                .filter(|f| matcher.matches(f))
+                .collect();
+            if !selected.is_empty() {
+                match_sets[index]
+                    .entry(selected)
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+
+        for (pattern, tools) in dead {
+            report.warning(
+                locations.remove(&pattern).unwrap_or_else(|| file.clone()),
+                format!(
+                    "Glob `{pattern}` matches no files in the working tree ({})",
+                    tools.iter().map(Tool::name).collect::<Vec<_>>().join(", ")
+                ),
+            );
+        }
+    }
+
+    // rules → (number of files, tools in which they match the same files)
+    let mut duplicates: BTreeMap<Vec<String>, (usize, Vec<Tool>)> = BTreeMap::new();
+    for (&tool, sets) in tools.iter().zip(match_sets) {
+        for (matched, rules) in sets {
+            if rules.len() > 1 {
+                let mut rules = rules;
+                rules.sort();
+                duplicates
+                    .entry(rules)
+                    .or_insert_with(|| (matched.len(), Vec::new()))
+                    .1
+                    .push(tool);
+            }
+        }
+    }
+    for (rules, (count, tools)) in duplicates {
+        let mut names = rules.iter().map(|r| format!("`{r}`")).collect::<Vec<_>>();
+        let last = names.pop().unwrap_or_default();
+        report.warning(
+            format!(".agentsync/rules/{}.md", rules[rules.len() - 1]),
+            format!(
+                "Rules {} and {last} match exactly the same {count} file(s) in {}; consider merging them",
+                names.join(", "),
+                tools.iter().map(Tool::name).collect::<Vec<_>>().join(", ")
+            ),
+        );
+    }
+
+    Ok(report)
+}
+
+/// Frontmatter key a glob pattern most likely came from, for its location
+fn match_key<'a>(map: &FrontmatterMap, pattern: &str) -> &'a str {
+    ["cursor:globs", "windsurf:globs", "copilot:applyTo", "globs"]
+        .into_iter()
+        .find(|key| {
+            map.values
+                .get(*key)
+                .is_some_and(|value| split_patterns(value).contains(&pattern))
+        })
+        .unwrap_or("globs")
+}
+
+/// Check `agentsync.json`, returning the parsed config if it could be read
+fn validate_config(
+    project_root: &Path,
+    config_path: &Path,
+    report: &mut ValidationReport,
+) -> Option<AgentSyncConfig> {
+    let file = display_relative(project_root, config_path);
+
+    if !config_path.exists() {
+        report.error(&file, "Configuration file not found");
+        return None;
+    }
+
+    let contents = match read_rule_file(config_path) {
+        Ok(c) => c,
+        Err(e) => {
+            report.error(&file, e.to_string());
+            return None;
+        }
+    };
+
+    let config: AgentSyncConfig = match serde_json::from_str(&contents) {
+        Ok(c) => c,
+        Err(e) => {
+            report.error(
+                format!("{file}:{}:{}", e.line(), e.column()),
+                format!("Invalid JSON: {e}"),
+            );
+            return None;
+        }
+    };
+
+    for err in config.validation_errors() {
+        report.error(&file, error_message(err));
+    }
+
+    Some(config)
+}
+
+/// Check a single source rule: syntax, unknown keys, targets, globs and tool configs
+fn validate_source_rule(
+    project_root: &Path,
+    rule_path: &Path,
+    enabled_tools: &[String],
+    report: &mut ValidationReport,
+) {
+    let file = display_relative(project_root, rule_path);
+
+    if extract_rule_name(rule_path).is_none() {
+        report.error(&file, "Invalid rule name");
+        return;
+    }
+
+    let content = match read_rule_file(rule_path) {
+        Ok(c) => c,
+        Err(e) => {
+            report.error(&file, e.to_string());
+            return;
+        }
+    };
+
+    // Syntax errors stop here; everything below needs a parsed rule
+    let map = match parse_frontmatter_map(&content, Some(&file)) {
+        Ok(map) => map,
+        Err(e) => {
+            report.error(error_location(&file, &e), error_message(e));
+            return;
+        }
+    };
+
+    if let Ok(issues) = check_frontmatter::<AgentSyncRule>(&content, Some(&file)) {
+        for issue in issues {
+            report.warning(
+                format!("{file}:{}:{}", issue.span.line, issue.span.column),
+                issue.message,
+            );
+        }
+    }
+
+    let rule = match parse_frontmatter::<AgentSyncRule>(&content, Some(&file)) {
+        Ok(rule) => rule.frontmatter,
+        Err(e) => {
+            report.error(error_location(&file, &e), error_message(e));
+            return;
+        }
+    };
+
+    validate_targets(&file, &map, &rule, enabled_tools, report);
+    validate_globs(&file, &map, report);
+    validate_tool_configs(&file, &map, &rule, report);
+}
+
+/// Location of a key in the rule file, falling back to the file itself
+fn key_location(file: &str, map: &FrontmatterMap, key: &str) -> String {
+    map.spans.get(key).map_or_else(
+        || file.to_string(),
+        |span| format!("{file}:{}:{}", span.line, span.column),
+    )
+}
+
+fn validate_targets(
+    file: &str,
+    map: &FrontmatterMap,
+    rule: &AgentSyncRule,
+    enabled_tools: &[String],
+    report: &mut ValidationReport,
+) {
+    let location = key_location(file, map, "targets");
+
+    if rule.targets.is_empty() {
+        report.error(
+            location,
+            "`targets` is empty, so the rule is never synced to any tool",
+        );
+        return;
+    }
+
+    // Invalid tool names are reported by the frontmatter check
+    for target in &rule.targets {
+        if target.parse::<Tool>().is_ok() && !enabled_tools.contains(target) {
+            report.warning(
+                &location,
+                format!("Rule targets `{target}`, which is not enabled in agentsync.json"),
+            );
+        }
+    }
+}
+
+fn validate_globs(file: &str, map: &FrontmatterMap, report: &mut ValidationReport) {
+    const GLOB_KEYS: &[&str] = &[
+        "globs",
+        "excludeGlobs",
+        "cursor:globs",
+        "windsurf:globs",
+        "copilot:applyTo",
+    ];
+
+    for key in GLOB_KEYS {
+        let Some(value) = map.values.get(*key) else {
+            continue;
+        };
+
+        for pattern in split_patterns(value) {
+            // Exclusions may be written with a leading `!`, as the tools spell them
+            let bare = pattern.strip_prefix('!').unwrap_or(pattern);
+            if let Err(e) = glob::Pattern::new(bare) {
+                report.error(
+                    key_location(file, map, key),
+                    format!(
+                        "Invalid glob `{pattern}` in `{}`: {e}",
+                        key.replace(':', ".")
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn validate_tool_configs(
+    file: &str,
+    map: &FrontmatterMap,
+    rule: &AgentSyncRule,
+    report: &mut ValidationReport,
+) {
+    let targets_all = rule.targets.iter().any(|t| t == "*");
+    let configured = [
+        ("cursor", rule.cursor.is_some()),
+        ("windsurf", rule.windsurf.is_some()),
+        ("copilot", rule.copilot.is_some()),
+    ];
+
+    for (tool, has_config) in configured {
+        if has_config && !targets_all && !rule.targets.iter().any(|t| t == tool) {
+            report.warning(
+                key_location(file, map, tool),
+                format!("`{tool}` settings are ignored because the rule does not target {tool}"),
+            );
+        }
+    }
+
+    if let Some(cursor) = &rule.cursor
+        && cursor.always_apply
+        && !cursor.globs.is_empty()
+    {
+        report.warning(
+            key_location(file, map, "cursor:globs"),
+            "`cursor.globs` is ignored because `cursor.alwaysApply` is true",
+        );
+    }
+
+    if let Some(windsurf) = &rule.windsurf
+        && windsurf.trigger == WindsurfTrigger::Glob
+        && windsurf.globs.is_empty()
+    {
+        report.error(
+            key_location(file, map, "windsurf:trigger"),
+            "`windsurf.trigger` is `glob` but `windsurf.globs` is empty, so the rule never applies",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Allow expect/unwrap in tests for brevity
+    #![allow(clippy::expect_used)]
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_report_counts_and_validity() {
+        let mut report = ValidationReport::new();
+        assert!(report.is_valid(true));
+
+        report.warning("rule.md", "warning");
+        assert!(report.is_valid(false));
+        assert!(!report.is_valid(true));
+
+        report.error("rule.md", "error");
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.warning_count(), 1);
+        assert!(!report.is_valid(false));
+    }
+
+    #[test]
+    fn test_validate_globs_reports_invalid_pattern() {
+        let mut map = FrontmatterMap::default();
+        map.values
+            .insert("globs".to_string(), "src/**/*.rs,src/[".to_string());
+
+        let mut report = ValidationReport::new();
+        validate_globs("rule.md", &map, &mut report);
+
+        assert_eq!(report.error_count(), 1);
+        assert!(report.problems[0].message.contains("`src/[`"));
+    }
+
+    #[test]
+    fn test_validate_globs_keeps_brace_lists_whole() {
+        let mut map = FrontmatterMap::default();
+        map.values.insert(
+            "globs".to_string(),
+            "src/**/*.{ts,tsx},src/{a,b}/[".to_string(),
+        );
+
+        let mut report = ValidationReport::new();
+        validate_globs("rule.md", &map, &mut report);
+
+        assert_eq!(report.error_count(), 1, "{:?}", report.problems);
+        assert!(report.problems[0].message.contains("`src/{a,b}/[`"));
+    }
+
+    #[test]
+    fn test_validate_globs_checks_exclusions() {
+        let mut map = FrontmatterMap::default();
+        map.values.insert(
+            "excludeGlobs".to_string(),
+            "!src/generated/**,src/[gen/**".to_string(),
+        );
+
+        let mut report = ValidationReport::new();
+        validate_globs("rule.md", &map, &mut report);
+
+        assert_eq!(report.error_count(), 1);
+        assert!(
+            report.problems[0]
+                .message
+                .contains("`src/[gen/**` in `excludeGlobs`")
+        );
+    }
+
+    #[test]
+    fn test_error_location_uses_line_and_column() {
+        let err = AgentSyncError::FrontmatterParse {
+            file: "rule.md".to_string(),
+            line: Some(3),
+            column: Some(2),
+            source_line: None,
+            message: "bad".to_string(),
+        };
+        assert_eq!(error_location("rule.md", &err), "rule.md:3:2");
+        assert_eq!(error_message(err), "bad");
+    }
+}
@@ -227,6 +227,9 @@ This is a test.
     let options = agentsync::sync::SyncOptions {
         dry_run: true,
         verbose: false,
+        strict: false,
+        keep_existing: false,
+        ..Default::default()
     };
 
     let result = ctx.sync_to_tools(&options);
@@ -338,3 +341,238 @@ This was modified in Cursor.
     assert!(updated_content.contains("Modified cursor rule"));
     assert!(updated_content.contains("This was modified in Cursor"));
 }
+
+#[test]
+fn test_sync_with_explicit_config_path() {
+    use clap::Parser;
+
+    let ctx = TestContext::new().init_project_with_tools(&["cursor"]);
+    ctx.create_agentsync_rule(
+        "config-rule",
+        &common::simple_agentsync_rule("Config rule", "**/*.rs"),
+    );
+
+    // The project root comes from the config location, not the current directory
+    let config_path = ctx.path("agentsync.json");
+    let cli = agentsync::Cli::try_parse_from([
+        "agentsync",
+        "sync",
+        "--config",
+        config_path.to_str().unwrap(),
+    ])
+    .expect("should parse --config after the subcommand");
+
+    agentsync::run(cli).expect("sync should succeed");
+    ctx.assert_rule_exists(Tool::Cursor, "config-rule");
+}
+
+#[test]
+fn test_rule_expectations_fail_in_one_tool() {
+    use clap::Parser;
+
+    let ctx = TestContext::new().init_project_with_tools(&["cursor", "copilot"]);
+    ctx.create_agentsync_rule(
+        "python",
+        "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"*.py\"\ncopilot:\n  applyTo: \"*.py\"\nexpect:\n  matches: [\"src/app.py\"]\n  excludes: [\"docs/index.md\"]\n---\n# Python\n",
+    );
+
+    let config_path = ctx.path("agentsync.json");
+    let cli = agentsync::Cli::try_parse_from([
+        "agentsync",
+        "test",
+        "--config",
+        config_path.to_str().unwrap(),
+    ])
+    .expect("should parse test");
+
+    // Copilot only matches its own `*.py` at the project root
+    let result = agentsync::run(cli);
+    assert!(matches!(
+        result,
+        Err(agentsync::AgentSyncError::ExpectationsFailed { failed: 1 })
+    ));
+}
+
+#[test]
+fn test_explicit_config_path_must_exist() {
+    use clap::Parser;
+
+    let ctx = TestContext::new();
+    let config_path = ctx.path("missing.json");
+    let cli = agentsync::Cli::try_parse_from([
+        "agentsync",
+        "--config",
+        config_path.to_str().unwrap(),
+        "validate",
+    ])
+    .expect("should parse --config");
+
+    let result = agentsync::run(cli);
+    assert!(matches!(
+        result,
+        Err(agentsync::AgentSyncError::ConfigNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_init_non_interactive_import_all() {
+    use clap::Parser;
+
+    let ctx = TestContext::new();
+    ctx.create_cursor_rule(
+        "cursor-rule",
+        &common::simple_cursor_rule("Cursor rule", true, ""),
+    );
+    ctx.create_windsurf_rule(
+        "windsurf-rule",
+        "---\ntrigger: always_on\n---\n\n# Windsurf rule\n",
+    );
+
+    let config_path = ctx.path("agentsync.json");
+    let cli = agentsync::Cli::try_parse_from([
+        "agentsync",
+        "--config",
+        config_path.to_str().unwrap(),
+        "init",
+        "--import",
+        "all",
+        "--tools",
+        "cursor,windsurf",
+    ])
+    .expect("should parse init flags");
+
+    agentsync::run(cli).expect("init should succeed");
+
+    assert_eq!(ctx.load_config().tools, ["cursor", "windsurf"]);
+    ctx.assert_rule_exists(Tool::AgentSync, "cursor-rule");
+    ctx.assert_rule_exists(Tool::AgentSync, "windsurf-rule");
+}
+
+#[test]
+fn test_init_rejects_unknown_tools() {
+    use clap::Parser;
+
+    let ctx = TestContext::new();
+    let config_path = ctx.path("agentsync.json");
+    let cli = agentsync::Cli::try_parse_from([
+        "agentsync",
+        "--config",
+        config_path.to_str().unwrap(),
+        "init",
+        "--no-import",
+        "--tools",
+        "cursor,vim",
+    ])
+    .expect("should parse init flags");
+
+    assert!(agentsync::run(cli).is_err());
+    assert!(!config_path.exists());
+    assert!(!ctx.path(".agentsync").exists());
+}
+
+#[test]
+fn test_init_import_conflicts_with_no_import() {
+    use clap::Parser;
+
+    let result =
+        agentsync::Cli::try_parse_from(["agentsync", "init", "--import", "cursor", "--no-import"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_add_from_project_template() {
+    use clap::Parser;
+
+    let ctx = TestContext::new().init_project();
+    let templates_dir = ctx.path(agentsync::templates::TEMPLATES_DIR);
+    fs::create_dir_all(&templates_dir).expect("Failed to create dir");
+    fs::write(
+        templates_dir.join("team.md"),
+        common::simple_agentsync_rule("Team conventions", "src/**/*.go"),
+    )
+    .expect("Failed to write template");
+
+    let config_path = ctx.path("agentsync.json");
+    let cli = agentsync::Cli::try_parse_from([
+        "agentsync",
+        "--config",
+        config_path.to_str().unwrap(),
+        "add",
+        "go-style",
+        "--template",
+        "team",
+    ])
+    .expect("should parse --template");
+
+    agentsync::run(cli).expect("add should succeed");
+
+    let content = ctx.read_rule(Tool::AgentSync, "go-style");
+    assert!(content.contains("description: Team conventions"));
+    assert!(content.contains("globs: src/**/*.go"));
+}
+
+#[test]
+fn test_add_template_with_mode_keeps_template_globs() {
+    use clap::Parser;
+
+    let ctx = TestContext::new().init_project();
+    let config_path = ctx.path("agentsync.json");
+    let add = |name: &str, mode: &str| {
+        agentsync::Cli::try_parse_from([
+            "agentsync",
+            "--config",
+            config_path.to_str().unwrap(),
+            "add",
+            name,
+            "--template",
+            "rust",
+            "--mode",
+            mode,
+        ])
+        .expect("should parse --template with --mode")
+    };
+
+    agentsync::run(add("rust-glob", "glob")).expect("add should succeed");
+    let content = ctx.read_rule(Tool::AgentSync, "rust-glob");
+    assert!(content.contains("activation: glob\n"), "{content}");
+    assert!(content.contains("globs: **/*.rs"), "{content}");
+
+    // A mode without globs leaves the template's globs out
+    agentsync::run(add("rust-always", "always")).expect("add should succeed");
+    let content = ctx.read_rule(Tool::AgentSync, "rust-always");
+    assert!(!content.contains("*.rs"), "{content}");
+}
+
+#[test]
+fn test_init_scaffolds_detected_starter_rules() {
+    use clap::Parser;
+
+    let ctx = TestContext::new();
+    fs::write(ctx.path("Cargo.toml"), "[package]\nname = \"demo\"\n").expect("Failed to write");
+    fs::write(ctx.path("go.mod"), "module example.com/demo\n").expect("Failed to write");
+
+    let config_path = ctx.path("agentsync.json");
+    let init = |scaffold: &str| {
+        agentsync::Cli::try_parse_from([
+            "agentsync",
+            "--config",
+            config_path.to_str().unwrap(),
+            "init",
+            "--scaffold",
+            scaffold,
+        ])
+        .expect("should parse --scaffold")
+    };
+
+    // Unknown names are rejected before anything is created
+    assert!(agentsync::run(init("python")).is_err());
+    assert!(!config_path.exists());
+
+    agentsync::run(init("all")).expect("init should succeed");
+
+    let rust = ctx.read_rule(Tool::AgentSync, "rust");
+    assert!(rust.contains("globs: **/*.rs"));
+    let go = ctx.read_rule(Tool::AgentSync, "go");
+    assert!(go.contains("globs: **/*.go"));
+    assert!(go.contains("# Go"));
+}
@@ -206,6 +206,9 @@ pub fn default_sync_options() -> SyncOptions {
     SyncOptions {
         dry_run: false,
         verbose: false,
+        strict: false,
+        keep_existing: false,
+        ..Default::default()
     }
 }
 
@@ -13,7 +13,7 @@ use agentsync::converter::{
     copilot_rule_to_agentsync, cursor_rule_to_agentsync, windsurf_rule_to_agentsync,
 };
 use agentsync::models::{
-    AgentSyncRule, CopilotRule, CursorRule, Rule, WindsurfRule, WindsurfTrigger,
+    Activation, AgentSyncRule, CopilotRule, CursorRule, Rule, WindsurfRule, WindsurfTrigger,
 };
 use agentsync::parser::{parse_frontmatter, serialize_frontmatter};
 
@@ -450,10 +450,14 @@ fn test_missing_tool_configs_use_fallback() {
     let agentsync = AgentSyncRule {
         targets: vec!["*".to_string()],
         description: "No tool configs".to_string(),
+        tags: Vec::new(),
+        activation: None,
         globs: "**/*.py".to_string(),
+        exclude_globs: String::new(),
         cursor: None,
         windsurf: None,
         copilot: None,
+        expect: None,
     };
 
     // Should use fallback logic based on global globs
@@ -466,3 +470,125 @@ fn test_missing_tool_configs_use_fallback() {
     let copilot = agentsync::converter::agentsync_to_copilot(&agentsync);
     assert_eq!(copilot.apply_to, "**/*.py");
 }
+
+// ============================================================================
+// Activation Tests
+// ============================================================================
+
+fn cursor_rule(description: &str, always_apply: bool, globs: &str) -> Rule<CursorRule> {
+    Rule {
+        frontmatter: CursorRule {
+            description: description.to_string(),
+            always_apply,
+            globs: globs.to_string(),
+        },
+        content: "# Rule\n".to_string(),
+    }
+}
+
+#[test]
+fn test_cursor_activation_combinations_roundtrip() {
+    let cases = [
+        (cursor_rule("", true, ""), Activation::Always),
+        (cursor_rule("", false, "**/*.tsx"), Activation::Glob),
+        (
+            cursor_rule("Components", false, ""),
+            Activation::ModelDecision,
+        ),
+        (
+            cursor_rule("Components", false, "**/*.tsx"),
+            Activation::GlobOrModel,
+        ),
+        (cursor_rule("", false, ""), Activation::Manual),
+    ];
+
+    for (original, activation) in cases {
+        let agentsync = cursor_rule_to_agentsync(&original);
+        assert_eq!(agentsync.frontmatter.activation, Some(activation));
+
+        // Through the AgentSync file format and back
+        let serialized = serialize_frontmatter(&agentsync).unwrap();
+        let reparsed: Rule<AgentSyncRule> = parse_frontmatter(&serialized, None).unwrap();
+        let back = agentsync_rule_to_cursor(&reparsed);
+        assert_eq!(original.frontmatter, back.frontmatter, "{activation}");
+    }
+}
+
+#[test]
+fn test_windsurf_activation_roundtrip() {
+    let triggers = [
+        (WindsurfTrigger::AlwaysOn, "", Activation::Always),
+        (WindsurfTrigger::Glob, "**/*.py", Activation::Glob),
+        (
+            WindsurfTrigger::ModelDecision,
+            "**/*.py",
+            Activation::ModelDecision,
+        ),
+        (WindsurfTrigger::Manual, "", Activation::Manual),
+    ];
+
+    for (trigger, globs, activation) in triggers {
+        // Always-on rules carry no description in Windsurf
+        let description = if activation == Activation::Always {
+            ""
+        } else {
+            "Python"
+        };
+        let original = Rule {
+            frontmatter: WindsurfRule {
+                trigger,
+                description: description.to_string(),
+                globs: globs.to_string(),
+            },
+            content: "# Python\n".to_string(),
+        };
+        let agentsync = windsurf_rule_to_agentsync(&original);
+        assert_eq!(agentsync.frontmatter.activation, Some(activation));
+
+        let back = agentsync_rule_to_windsurf(&agentsync);
+        assert_eq!(original.frontmatter, back.frontmatter, "{activation}");
+    }
+}
+
+#[test]
+fn test_explicit_activation_maps_to_every_tool() {
+    let rule = AgentSyncRule {
+        targets: vec!["*".to_string()],
+        description: "Components".to_string(),
+        tags: Vec::new(),
+        activation: Some(Activation::GlobOrModel),
+        globs: "**/*.tsx".to_string(),
+        exclude_globs: String::new(),
+        cursor: None,
+        windsurf: None,
+        copilot: None,
+        expect: None,
+    };
+
+    let cursor = agentsync::converter::agentsync_to_cursor(&rule);
+    assert_eq!(cursor.description, "Components");
+    assert_eq!(cursor.globs, "**/*.tsx");
+    assert!(!cursor.always_apply);
+
+    let windsurf = agentsync::converter::agentsync_to_windsurf(&rule);
+    assert_eq!(windsurf.trigger, WindsurfTrigger::Glob);
+    assert_eq!(windsurf.globs, "**/*.tsx");
+
+    let copilot = agentsync::converter::agentsync_to_copilot(&rule);
+    assert_eq!(copilot.apply_to, "**/*.tsx");
+
+    let manual = AgentSyncRule {
+        activation: Some(Activation::Manual),
+        ..rule
+    };
+    let cursor = agentsync::converter::agentsync_to_cursor(&manual);
+    assert!(cursor.description.is_empty() && cursor.globs.is_empty());
+    assert_eq!(
+        agentsync::converter::agentsync_to_windsurf(&manual).trigger,
+        WindsurfTrigger::Manual
+    );
+    assert_eq!(
+        agentsync::converter::agentsync_to_copilot(&manual).apply_to,
+        "**"
+    );
+}
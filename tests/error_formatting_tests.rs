@@ -140,6 +140,8 @@ fn test_invalid_frontmatter_formatting() {
     let err = AgentSyncError::FrontmatterParse {
         file: "test-rule.md".to_string(),
         line: Some(5),
+        column: None,
+        source_line: None,
         message: "Missing closing '---' delimiter".to_string(),
     };
     let msg = err.to_string();
@@ -164,6 +166,8 @@ fn test_invalid_frontmatter_without_line_number() {
     let err = AgentSyncError::FrontmatterParse {
         file: "test-rule.md".to_string(),
         line: None,
+        column: None,
+        source_line: None,
         message: "Missing opening '---' delimiter".to_string(),
     };
     let msg = err.to_string();
@@ -177,6 +181,30 @@ fn test_invalid_frontmatter_without_line_number() {
     assert!(msg.contains("test-rule.md"));
 }
 
+#[test]
+fn test_invalid_frontmatter_with_source_snippet() {
+    let err = AgentSyncError::FrontmatterParse {
+        file: "test-rule.md".to_string(),
+        line: Some(4),
+        column: Some(3),
+        source_line: Some("  alwaysApply true".to_string()),
+        message: "Expected `key: value`, found `alwaysApply true`".to_string(),
+    };
+    let msg = err.to_string();
+
+    // Check location in header and rustc-style arrow
+    assert!(msg.contains("line 4, column 3"));
+    assert!(msg.contains("-->"));
+    assert!(msg.contains("test-rule.md:4:3"));
+
+    // Check the offending line and caret are rendered
+    assert!(msg.contains("  alwaysApply true"));
+    assert!(msg.contains('^'));
+
+    // Hints are still present
+    assert!(msg.contains("Example format"));
+}
+
 #[test]
 fn test_conversion_failed_formatting() {
     let source = AgentSyncError::Other("parse error".to_string());
@@ -204,6 +232,38 @@ fn test_conversion_failed_formatting() {
     assert!(msg.contains("agentsync validate --tool cursor"));
 }
 
+#[test]
+fn test_validation_failed_formatting() {
+    let err = AgentSyncError::ValidationFailed {
+        errors: 2,
+        warnings: 1,
+    };
+    let msg = err.to_string();
+
+    assert!(msg.contains("Validation failed with 2 error(s) and 1 warning(s)"));
+    assert!(msg.contains("agentsync validate"));
+    assert!(!msg.contains("--strict"));
+
+    // Warnings alone only fail under --strict, so explain why
+    let strict_err = AgentSyncError::ValidationFailed {
+        errors: 0,
+        warnings: 3,
+    };
+    assert!(strict_err.to_string().contains("--strict"));
+}
+
+#[test]
+fn test_rule_not_found_formatting() {
+    let err = AgentSyncError::RuleNotFound {
+        name: "missing-rule".to_string(),
+    };
+    let msg = err.to_string();
+
+    assert!(msg.contains("Rule not found"));
+    assert!(msg.contains("missing-rule"));
+    assert!(msg.contains("agentsync list"));
+}
+
 #[test]
 fn test_error_display_preserves_colors() {
     // This test verifies that color codes are present in the output
@@ -0,0 +1,103 @@
+//! Integration tests for `agentsync status`
+
+// Allow expect/unwrap in tests for brevity
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use agentsync::fs::Tool;
+use agentsync::status::{RuleState, project_status};
+use common::{TestContext, default_sync_options, simple_agentsync_rule};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_status_after_sync_is_clean() {
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule("rust", &simple_agentsync_rule("Rust rule", "**/*.rs"));
+
+    let before = project_status(
+        ctx.root(),
+        &[Tool::Cursor, Tool::Copilot, Tool::Windsurf],
+        &BTreeMap::new(),
+        &BTreeMap::new(),
+    )
+    .expect("should compute status");
+    assert!(!before.is_clean());
+    assert!(
+        before.rules[0]
+            .tools
+            .values()
+            .all(|state| *state == RuleState::Missing)
+    );
+
+    ctx.sync_to_tools(&default_sync_options());
+
+    let after = project_status(
+        ctx.root(),
+        &[Tool::Cursor, Tool::Copilot, Tool::Windsurf],
+        &BTreeMap::new(),
+        &BTreeMap::new(),
+    )
+    .expect("should compute status");
+    assert!(after.is_clean(), "{after:?}");
+}
+
+#[test]
+fn test_status_reports_unmanaged_tool_rules() {
+    let ctx = TestContext::new().init_project_with_tools(&["copilot"]);
+    ctx.create_copilot_rule(
+        "legacy",
+        "---\ndescription: \"Legacy\"\napplyTo: \"**/*.js\"\n---\n\n# Legacy\n",
+    );
+
+    let report = project_status(
+        ctx.root(),
+        &[Tool::Copilot],
+        &BTreeMap::new(),
+        &BTreeMap::new(),
+    )
+    .expect("should compute status");
+
+    assert_eq!(report.rules.len(), 1);
+    assert_eq!(report.rules[0].name, "legacy");
+    assert!(!report.rules[0].has_source);
+    assert_eq!(report.rules[0].tools["copilot"], RuleState::Unmanaged);
+}
+
+#[test]
+fn test_status_skips_rules_blocked_by_fidelity_policy() {
+    use agentsync::models::FidelityPolicy;
+    use agentsync::sync::SyncOptions;
+
+    let ctx = TestContext::new().init_project_with_tools(&["cursor", "copilot"]);
+    ctx.create_agentsync_rule(
+        "sql",
+        "---\ntargets: [\"*\"]\ndescription: SQL review\nactivation: model_decision\n---\n\n# SQL\n",
+    );
+    let fidelity: BTreeMap<String, FidelityPolicy> =
+        [("copilot".to_string(), FidelityPolicy::Skip)].into();
+    let tools = [Tool::Cursor, Tool::Copilot];
+
+    // A file from an earlier approximate sync is removed by the next sync
+    ctx.sync_to_tools(&default_sync_options());
+    let report = project_status(ctx.root(), &tools, &BTreeMap::new(), &fidelity)
+        .expect("should compute status");
+    assert_eq!(report.rules[0].tools["copilot"], RuleState::SourceAhead);
+
+    ctx.sync_to_tools(&SyncOptions {
+        fidelity: fidelity.clone(),
+        ..default_sync_options()
+    });
+    let report = project_status(ctx.root(), &tools, &BTreeMap::new(), &fidelity)
+        .expect("should compute status");
+    assert_eq!(report.rules[0].tools["copilot"], RuleState::Skipped);
+    assert!(report.is_clean(), "{report:?}");
+    assert!(
+        report
+            .budget
+            .tools
+            .iter()
+            .all(|budget| budget.tool != "copilot")
+    );
+}
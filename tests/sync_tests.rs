@@ -9,6 +9,7 @@ mod common;
 use agentsync::fs::Tool;
 use agentsync::sync::SyncOptions;
 use common::{TestContext, assert_sync_result, default_sync_options, simple_agentsync_rule};
+use std::collections::BTreeMap;
 
 #[test]
 fn test_sync_to_tools_basic() {
@@ -78,6 +79,9 @@ Dry run test.
     let options = SyncOptions {
         dry_run: true,
         verbose: false,
+        strict: false,
+        keep_existing: false,
+        ..Default::default()
     };
     let result = ctx.sync_to_tools(&options);
 
@@ -224,6 +228,9 @@ globs: "**/*.rs"
     let options = SyncOptions {
         dry_run: true,
         verbose: false,
+        strict: false,
+        keep_existing: false,
+        ..Default::default()
     };
     let result = ctx.sync_from_tool(Tool::Cursor, &options);
 
@@ -264,3 +271,541 @@ Original content from Cursor.
     ctx.assert_rule_exists(Tool::Copilot, "roundtrip");
     ctx.assert_rule_exists(Tool::Windsurf, "roundtrip");
 }
+
+#[test]
+fn test_sync_to_tools_warns_on_unknown_keys() {
+    let ctx = TestContext::new().init_project_with_tools(&["cursor"]);
+
+    let rule_content = r#"---
+targets: ["cursor"]
+description: "Typo rule"
+cursor:
+  alwaysapply: true
+---
+
+# Typo
+"#;
+    ctx.create_agentsync_rule("typo", rule_content);
+
+    let result = ctx.sync_to_tools(&default_sync_options());
+
+    // The rule still syncs, but the typo is reported
+    assert_sync_result(&result, 1, 0, 0, 0);
+    assert_eq!(result.warnings.len(), 1);
+    assert!(
+        result.warnings[0]
+            .1
+            .contains("did you mean `cursor.alwaysApply`?")
+    );
+}
+
+#[test]
+fn test_sync_to_tools_strict_rejects_unknown_keys() {
+    let ctx = TestContext::new().init_project_with_tools(&["cursor"]);
+
+    let rule_content = r#"---
+targets: ["cursor"]
+description: "Typo rule"
+cursor:
+  alwaysapply: true
+---
+
+# Typo
+"#;
+    ctx.create_agentsync_rule("typo", rule_content);
+
+    let options = SyncOptions {
+        dry_run: false,
+        verbose: false,
+        strict: true,
+        keep_existing: false,
+        ..Default::default()
+    };
+    let result = ctx.sync_to_tools(&options);
+
+    assert_sync_result(&result, 0, 0, 0, 1);
+    assert!(result.errors[0].1.contains("line 5, column 3"));
+    ctx.assert_rule_not_exists(Tool::Cursor, "typo");
+}
+
+#[test]
+fn test_sync_from_tool_strict_rejects_invalid_trigger() {
+    let ctx = TestContext::new().init_project();
+
+    let windsurf_rule = r"---
+trigger: alwayson
+description: Windsurf rule
+---
+
+# Windsurf
+";
+    ctx.create_windsurf_rule("bad-trigger", windsurf_rule);
+
+    let lenient = ctx.sync_from_tool(Tool::Windsurf, &default_sync_options());
+    assert_eq!(lenient.warnings.len(), 1);
+    assert!(lenient.warnings[0].1.contains("did you mean `always_on`?"));
+
+    let options = SyncOptions {
+        dry_run: true,
+        verbose: false,
+        strict: true,
+        keep_existing: false,
+        ..Default::default()
+    };
+    let strict = ctx.sync_from_tool(Tool::Windsurf, &options);
+    assert_sync_result(&strict, 0, 0, 0, 1);
+}
+
+#[test]
+fn test_import_from_tools_first_tool_wins() {
+    let ctx = TestContext::new().init_project();
+
+    ctx.create_cursor_rule(
+        "shared",
+        &common::simple_cursor_rule("From cursor", false, "**/*.rs"),
+    );
+    ctx.create_cursor_rule(
+        "cursor-only",
+        &common::simple_cursor_rule("Cursor only", true, ""),
+    );
+    ctx.create_windsurf_rule(
+        "shared",
+        "---\ntrigger: glob\nglobs: \"**/*.py\"\n---\n\n# From windsurf\n",
+    );
+
+    let result = agentsync::sync::import_from_tools(
+        ctx.root(),
+        &[Tool::Cursor, Tool::Windsurf],
+        &default_sync_options(),
+    )
+    .expect("import should succeed");
+
+    assert_eq!(result.added.len(), 2);
+    assert!(result.added.contains(&"shared (cursor)".to_string()));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].0, "shared (windsurf)");
+
+    let content = ctx.read_rule(Tool::AgentSync, "shared");
+    assert!(content.contains("From cursor"));
+    assert!(!content.contains("From windsurf"));
+}
+
+#[test]
+fn test_sync_to_tools_fidelity_policies() {
+    use agentsync::models::FidelityPolicy;
+
+    let ctx = TestContext::new().init_project();
+    let manual_rule = r#"---
+targets: ["*"]
+description: ""
+globs: "**/*"
+windsurf:
+  trigger: manual
+  globs: ""
+---
+
+# Manual rule
+"#;
+    ctx.create_agentsync_rule("manual-rule", manual_rule);
+
+    // Default policy: approximate, write and report
+    let result = ctx.sync_to_tools(&default_sync_options());
+    assert_eq!(result.lossy.len(), 1);
+    assert_eq!(result.lossy[0].rule, "manual-rule (copilot)");
+    assert_eq!(result.lossy[0].policy, FidelityPolicy::Approximate);
+    ctx.assert_rule_exists(Tool::Copilot, "manual-rule");
+    ctx.assert_rule_exists(Tool::Windsurf, "manual-rule");
+
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule("manual-rule", manual_rule);
+    let options = SyncOptions {
+        fidelity: [("copilot".to_string(), FidelityPolicy::Skip)].into(),
+        ..default_sync_options()
+    };
+    let result = ctx.sync_to_tools(&options);
+    assert_eq!(result.lossy[0].policy, FidelityPolicy::Skip);
+    assert_eq!(result.fidelity_failures(), 0);
+    ctx.assert_rule_not_exists(Tool::Copilot, "manual-rule");
+    ctx.assert_rule_exists(Tool::Windsurf, "manual-rule");
+
+    let options = SyncOptions {
+        fidelity: [("copilot".to_string(), FidelityPolicy::Fail)].into(),
+        ..default_sync_options()
+    };
+    let result = ctx.sync_to_tools(&options);
+    assert_eq!(result.fidelity_failures(), 1);
+    ctx.assert_rule_not_exists(Tool::Copilot, "manual-rule");
+}
+
+#[test]
+fn test_blocked_rule_removes_earlier_approximate_output() {
+    use agentsync::models::FidelityPolicy;
+
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule(
+        "manual-rule",
+        "---\ntargets: [\"*\"]\nactivation: manual\n---\n\n# Manual rule\n",
+    );
+
+    ctx.sync_to_tools(&default_sync_options());
+    ctx.assert_rule_exists(Tool::Copilot, "manual-rule");
+
+    let options = SyncOptions {
+        fidelity: [("copilot".to_string(), FidelityPolicy::Skip)].into(),
+        ..default_sync_options()
+    };
+    let result = ctx.sync_to_tools(&options);
+    assert_eq!(result.removed, ["manual-rule (copilot)"]);
+    assert!(result.has_changes());
+    ctx.assert_rule_not_exists(Tool::Copilot, "manual-rule");
+    ctx.assert_rule_exists(Tool::Windsurf, "manual-rule");
+
+    // Nothing left to remove
+    let result = ctx.sync_to_tools(&options);
+    assert!(result.removed.is_empty());
+}
+
+#[test]
+fn test_imported_fixtures_roundtrip_through_every_tool() {
+    use agentsync::roundtrip::{RoundtripState, verify_roundtrip};
+
+    let fixtures = [
+        (
+            Tool::Cursor,
+            "python-dev",
+            include_str!("fixtures/cursor/python-dev.mdc"),
+        ),
+        (
+            Tool::Cursor,
+            "react-components",
+            include_str!("fixtures/cursor/react-components.mdc"),
+        ),
+        (
+            Tool::Windsurf,
+            "python-dev",
+            include_str!("fixtures/windsurf/python-dev.md"),
+        ),
+        (
+            Tool::Copilot,
+            "python-standards",
+            include_str!("fixtures/copilot/python-standards.instructions.md"),
+        ),
+    ];
+    let tools = [Tool::Cursor, Tool::Windsurf, Tool::Copilot];
+
+    for (tool, name, content) in fixtures {
+        let ctx = TestContext::new().init_project();
+        ctx.create_rule(tool, name, content);
+        ctx.sync_from_tool(tool, &default_sync_options());
+
+        let report = verify_roundtrip(ctx.root(), &tools).expect("should verify");
+        assert!(!report.checks.is_empty());
+        assert!(
+            report
+                .checks
+                .iter()
+                .all(|check| check.state != RoundtripState::Changed),
+            "{name} imported from {tool} changed: {report:#?}"
+        );
+    }
+}
+
+#[test]
+fn test_size_limits_per_tool() {
+    use agentsync::budget::Limit;
+    use agentsync::models::{LimitPolicy, ToolLimits};
+
+    let ctx = TestContext::new().init_project();
+    let big_body = "Use descriptive names.\n".repeat(300);
+    ctx.create_agentsync_rule(
+        "big",
+        &format!("---\ntargets: [\"*\"]\nactivation: always\n---\n\n{big_body}"),
+    );
+    ctx.create_agentsync_rule("small", &simple_agentsync_rule("Small rule", "**/*.rs"));
+
+    // Windsurf's built-in per-file limit warns, the file is still written
+    let result = ctx.sync_to_tools(&default_sync_options());
+    let violations = result.budget.violations();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].tool, "windsurf");
+    assert_eq!(violations[0].rule.as_deref(), Some("big"));
+    assert_eq!(violations[0].limit, Limit::FileChars);
+    assert_eq!(result.budget.failures(), 0);
+    ctx.assert_rule_exists(Tool::Windsurf, "big");
+
+    let cursor = result
+        .budget
+        .tools
+        .iter()
+        .find(|budget| budget.tool == "cursor")
+        .expect("cursor should have a budget");
+    assert_eq!(cursor.rules.len(), 2);
+    assert_eq!(cursor.always_on().count(), 1);
+    assert!(cursor.always_on_tokens() > big_body.len() / 4);
+
+    // `fail` refuses to write the oversized file and fails the sync
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule(
+        "big",
+        &format!("---\ntargets: [\"*\"]\nactivation: always\n---\n\n{big_body}"),
+    );
+    let options = SyncOptions {
+        limits: [
+            (
+                "windsurf".to_string(),
+                ToolLimits {
+                    on_exceed: LimitPolicy::Fail,
+                    ..ToolLimits::default()
+                },
+            ),
+            (
+                "cursor".to_string(),
+                ToolLimits {
+                    max_always_on_tokens: Some(1000),
+                    ..ToolLimits::default()
+                },
+            ),
+        ]
+        .into(),
+        ..default_sync_options()
+    };
+    let result = ctx.sync_to_tools(&options);
+    assert_eq!(result.budget.failures(), 1);
+    ctx.assert_rule_not_exists(Tool::Windsurf, "big");
+    ctx.assert_rule_exists(Tool::Cursor, "big");
+    assert!(
+        result
+            .budget
+            .violations()
+            .iter()
+            .any(|violation| violation.tool == "cursor" && violation.limit == Limit::AlwaysOnTokens)
+    );
+}
+
+#[test]
+fn test_fail_on_total_limit_writes_nothing_for_the_tool() {
+    use agentsync::budget::Limit;
+    use agentsync::models::{LimitPolicy, ToolLimits};
+
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule("rust", &simple_agentsync_rule("Rust rule", "**/*.rs"));
+    ctx.sync_to_tools(&default_sync_options());
+    let before = ctx.read_rule(Tool::Windsurf, "rust");
+
+    ctx.create_agentsync_rule("python", &simple_agentsync_rule("Python rule", "**/*.py"));
+    ctx.create_agentsync_rule(
+        "rust",
+        &simple_agentsync_rule("Rust rule, edited", "**/*.rs"),
+    );
+    let options = SyncOptions {
+        limits: [(
+            "windsurf".to_string(),
+            ToolLimits {
+                max_total_chars: Some(150),
+                on_exceed: LimitPolicy::Fail,
+                ..ToolLimits::default()
+            },
+        )]
+        .into(),
+        ..default_sync_options()
+    };
+    let result = ctx.sync_to_tools(&options);
+
+    let violations = result.budget.violations();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].limit, Limit::TotalChars);
+    assert_eq!(result.budget.failures(), 1);
+    assert_eq!(result.warnings[0].0, "windsurf");
+
+    // Windsurf is left as it was; other tools are synced
+    ctx.assert_rule_not_exists(Tool::Windsurf, "python");
+    assert_eq!(ctx.read_rule(Tool::Windsurf, "rust"), before);
+    ctx.assert_rule_exists(Tool::Cursor, "python");
+    assert!(ctx.read_rule(Tool::Cursor, "rust").contains("edited"));
+    assert!(
+        result
+            .added
+            .iter()
+            .all(|name| !name.ends_with("(windsurf)"))
+    );
+    // Both windsurf rules are reported as not written, not as up to date
+    let mut withheld = result.withheld.clone();
+    withheld.sort();
+    assert_eq!(withheld, ["python (windsurf)", "rust (windsurf)"]);
+}
+
+#[test]
+fn test_split_part_names_never_overwrite_source_rules() {
+    use agentsync::manage::existing_rule_files;
+    use agentsync::models::{LimitPolicy, ToolLimits};
+    use agentsync::status::{RuleState, project_status};
+
+    let ctx = TestContext::new().init_project_with_tools(&["windsurf"]);
+    let body = format!(
+        "\n# Style\n\n{}\n## Errors\n\n{}",
+        "Keep it short.\n".repeat(10),
+        "Use `?`.\n".repeat(10)
+    );
+    ctx.create_agentsync_rule(
+        "style",
+        &format!("---\ntargets: [\"*\"]\nactivation: always\n---\n{body}"),
+    );
+    ctx.create_agentsync_rule(
+        "style-1",
+        "---\ntargets: [\"*\"]\nactivation: always\n---\n\n# First style\n",
+    );
+    let limits: std::collections::BTreeMap<String, ToolLimits> = [(
+        "windsurf".to_string(),
+        ToolLimits {
+            max_file_chars: Some(200),
+            on_exceed: LimitPolicy::Split,
+            ..ToolLimits::default()
+        },
+    )]
+    .into();
+    let options = SyncOptions {
+        limits: limits.clone(),
+        ..default_sync_options()
+    };
+
+    // `style` is written whole and reported, `style-1` keeps its own content
+    let result = ctx.sync_to_tools(&options);
+    let mut added = result.added.clone();
+    added.sort();
+    assert_eq!(added, ["style (windsurf)", "style-1 (windsurf)"]);
+    assert_eq!(
+        result.warnings,
+        [(
+            "style (windsurf)".to_string(),
+            "not split: part `style-1` would overwrite the rule of that name".to_string()
+        )]
+    );
+    assert_eq!(result.budget.violations().len(), 1);
+    assert!(
+        ctx.read_rule(Tool::Windsurf, "style-1")
+            .contains("# First style")
+    );
+
+    // Nothing flip-flops on the next sync
+    let result = ctx.sync_to_tools(&options);
+    assert!(result.updated.is_empty(), "{:?}", result.updated);
+    assert!(result.removed.is_empty());
+
+    let status = project_status(ctx.root(), &[Tool::Windsurf], &limits, &BTreeMap::new())
+        .expect("should compute status");
+    assert!(
+        status
+            .rules
+            .iter()
+            .all(|rule| rule.tools["windsurf"] == RuleState::InSync),
+        "{status:#?}"
+    );
+
+    // `style-1` is not a part of `style`
+    assert_eq!(
+        existing_rule_files(ctx.root(), "style")
+            .expect("should find files")
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_split_rules_are_tracked_as_a_group() {
+    use agentsync::manage::{apply_moves, existing_rule_files, plan_rename};
+    use agentsync::models::{LimitPolicy, ToolLimits};
+    use agentsync::status::{RuleState, project_status};
+
+    let ctx = TestContext::new().init_project_with_tools(&["cursor", "windsurf"]);
+    let section = |title: &str| format!("## {title}\n\n{}\n", "Keep it short.\n".repeat(20));
+    let body = format!(
+        "\n# Style\n\n{}{}{}",
+        section("Naming"),
+        section("Errors"),
+        section("Tests")
+    );
+    ctx.create_agentsync_rule(
+        "style",
+        &format!("---\ntargets: [\"*\"]\nactivation: always\n---\n{body}"),
+    );
+    let limits = |max| -> std::collections::BTreeMap<String, ToolLimits> {
+        [(
+            "windsurf".to_string(),
+            ToolLimits {
+                max_file_chars: Some(max),
+                on_exceed: LimitPolicy::Split,
+                ..ToolLimits::default()
+            },
+        )]
+        .into()
+    };
+    let options = |max| SyncOptions {
+        limits: limits(max),
+        ..default_sync_options()
+    };
+
+    // Only Windsurf is split, every part keeps the activation
+    let result = ctx.sync_to_tools(&options(500));
+    assert_eq!(
+        result.added,
+        [
+            "style (cursor)",
+            "style-1 (windsurf)",
+            "style-2 (windsurf)",
+            "style-3 (windsurf)"
+        ]
+    );
+    assert!(result.budget.violations().is_empty());
+    ctx.assert_rule_exists(Tool::Cursor, "style");
+    ctx.assert_rule_not_exists(Tool::Windsurf, "style");
+    for part in ["style-1", "style-2", "style-3"] {
+        assert!(
+            ctx.read_rule(Tool::Windsurf, part)
+                .contains("trigger: always_on")
+        );
+    }
+
+    let status = project_status(
+        ctx.root(),
+        &[Tool::Cursor, Tool::Windsurf],
+        &limits(500),
+        &BTreeMap::new(),
+    )
+    .expect("should compute status");
+    assert_eq!(status.rules.len(), 1);
+    assert_eq!(status.rules[0].tools["windsurf"], RuleState::InSync);
+
+    // A larger limit needs fewer parts; the leftover part is removed
+    let result = ctx.sync_to_tools(&options(900));
+    assert_eq!(result.removed, ["style-3 (windsurf)"]);
+    ctx.assert_rule_not_exists(Tool::Windsurf, "style-3");
+
+    // Importing joins the parts back into the source rule
+    let imported = ctx.sync_from_tool(Tool::Windsurf, &default_sync_options());
+    assert!(imported.errors.is_empty(), "{:?}", imported.errors);
+    assert_eq!(imported.updated, ["style"]);
+    let source = ctx.read_rule(Tool::AgentSync, "style");
+    assert!(source.ends_with(&body), "{source}");
+    assert!(!source.contains("agentsync:part"));
+
+    // Rename and remove carry the parts along
+    let moves = plan_rename(ctx.root(), "style", "conventions").expect("should plan rename");
+    assert_eq!(moves.len(), 4);
+    apply_moves(&moves).expect("should rename");
+    ctx.assert_rule_exists(Tool::Windsurf, "conventions-1");
+    ctx.assert_rule_exists(Tool::Windsurf, "conventions-2");
+    assert_eq!(
+        existing_rule_files(ctx.root(), "conventions")
+            .expect("should find files")
+            .len(),
+        4
+    );
+
+    // Without the split policy the whole file replaces the parts
+    let result = ctx.sync_to_tools(&default_sync_options());
+    assert_eq!(
+        result.removed,
+        ["conventions-1 (windsurf)", "conventions-2 (windsurf)"]
+    );
+    ctx.assert_rule_exists(Tool::Windsurf, "conventions");
+}
@@ -0,0 +1,230 @@
+//! Integration tests for project validation
+
+// Allow expect/unwrap in tests for brevity
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use agentsync::commands::run_validate;
+use agentsync::error::AgentSyncError;
+use agentsync::fs::Tool;
+use agentsync::validate::{Severity, validate_project, validate_tool_rules};
+use common::{TestContext, simple_agentsync_rule};
+use fs_err as fs;
+
+#[test]
+fn test_validate_clean_project() {
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule("rust", &simple_agentsync_rule("Rust rule", "**/*.rs"));
+
+    let report = validate_project(ctx.root(), &ctx.path("agentsync.json")).unwrap();
+
+    assert_eq!(report.rules_checked, 1);
+    assert!(report.problems.is_empty(), "{:?}", report.problems);
+    assert!(report.is_valid(true));
+}
+
+#[test]
+fn test_validate_reports_all_problems_at_once() {
+    let ctx = TestContext::new().init_project_with_tools(&["cursor", "cursr"]);
+
+    // Syntax error
+    ctx.create_agentsync_rule("broken", "---\ndescription: Broken\n  orphan: true\n---\n");
+
+    // Unknown key, bad glob, and windsurf settings on a cursor-only rule
+    ctx.create_agentsync_rule(
+        "messy",
+        r#"---
+targets: ["cursor"]
+description: "Messy rule"
+globs: "src/[.rs"
+cursor:
+  alwaysapply: true
+windsurf:
+  trigger: glob
+  globs: ""
+---
+
+# Messy
+"#,
+    );
+
+    let report = validate_project(ctx.root(), &ctx.path("agentsync.json")).unwrap();
+    let messages: Vec<_> = report.problems.iter().map(|p| p.message.as_str()).collect();
+
+    assert_eq!(report.rules_checked, 2);
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains("Invalid tool name: 'cursr'"))
+    );
+    assert!(messages.iter().any(|m| m.contains("has no parent key")));
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains("did you mean `cursor.alwaysApply`?"))
+    );
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains("Invalid glob `src/[.rs`"))
+    );
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains("`windsurf` settings are ignored"))
+    );
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains("`windsurf.globs` is empty"))
+    );
+    assert!(!report.is_valid(false));
+}
+
+#[test]
+fn test_validate_problem_locations() {
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule(
+        "typo",
+        "---\ntargets: [\"*\"]\ndescripton: Typo\n---\n\n# Typo\n",
+    );
+
+    let report = validate_project(ctx.root(), &ctx.path("agentsync.json")).unwrap();
+
+    assert_eq!(report.problems.len(), 1);
+    assert_eq!(report.problems[0].severity, Severity::Warning);
+    assert!(
+        report.problems[0]
+            .location
+            .ends_with(".agentsync/rules/typo.md:3:1")
+    );
+}
+
+#[test]
+fn test_validate_warns_on_targets_not_enabled() {
+    let ctx = TestContext::new().init_project_with_tools(&["cursor"]);
+    ctx.create_agentsync_rule(
+        "copilot-only",
+        "---\ntargets: [\"copilot\"]\ndescription: Copilot\n---\n\n# Copilot\n",
+    );
+
+    let report = validate_project(ctx.root(), &ctx.path("agentsync.json")).unwrap();
+
+    assert_eq!(report.error_count(), 0);
+    assert_eq!(report.warning_count(), 1);
+    assert!(report.is_valid(false));
+    assert!(!report.is_valid(true));
+}
+
+#[test]
+fn test_validate_malformed_config_still_checks_rules() {
+    let ctx = TestContext::new().init_project();
+    fs::write(ctx.path("agentsync.json"), "{ \"tools\": [").unwrap();
+    ctx.create_agentsync_rule("typo", "---\ndescripton: Typo\n---\n");
+
+    let report = validate_project(ctx.root(), &ctx.path("agentsync.json")).unwrap();
+
+    assert_eq!(report.error_count(), 1);
+    assert_eq!(report.warning_count(), 1);
+    assert!(report.problems[0].message.contains("Invalid JSON"));
+    assert!(report.problems[0].location.starts_with("agentsync.json:"));
+}
+
+#[test]
+fn test_validate_tool_rules() {
+    let ctx = TestContext::new().init_project();
+    ctx.create_cursor_rule(
+        "good",
+        "---\ndescription: Good\nalwaysApply: true\n---\n\n# Good\n",
+    );
+    ctx.create_cursor_rule(
+        "bad",
+        "---\ndescription: Bad\nalwaysApply: sometimes\n---\n\n# Bad\n",
+    );
+
+    let report = validate_tool_rules(ctx.root(), Tool::Cursor).unwrap();
+
+    assert_eq!(report.rules_checked, 2);
+    assert_eq!(report.error_count(), 0);
+    assert_eq!(report.warning_count(), 1);
+    assert!(report.problems[0].location.contains("bad.mdc:3:"));
+    assert!(report.is_valid(false));
+    assert!(!report.is_valid(true));
+}
+
+#[test]
+fn test_validate_glob_coverage() {
+    use agentsync::validate::validate_glob_coverage;
+
+    let ctx = TestContext::new().init_project_with_tools(&["cursor", "copilot"]);
+    for file in ["src/main.rs", "src/lib.rs", "ignored/app.tsx"] {
+        let path = ctx.path(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+    fs::write(ctx.path(".gitignore"), "ignored/\n").unwrap();
+
+    ctx.create_agentsync_rule(
+        "rust",
+        "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"src/**/*.rs\"\n---\n",
+    );
+    ctx.create_agentsync_rule(
+        "rust-style",
+        "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"**/*.rs\"\n---\n",
+    );
+    ctx.create_agentsync_rule(
+        "react",
+        "---\ntargets: [\"cursor\"]\nactivation: glob\nglobs: \"src/**/*.rs,**/*.tsx\"\n---\n",
+    );
+    // A narrower rule refining a broader one is not a duplicate
+    ctx.create_agentsync_rule(
+        "entry-point",
+        "---\ntargets: [\"*\"]\nactivation: glob\nglobs: \"src/main.rs\"\n---\n",
+    );
+    // Not attached by globs, so never checked
+    ctx.create_agentsync_rule(
+        "docs",
+        "---\ntargets: [\"*\"]\nactivation: manual\nglobs: \"**/*.md\"\n---\n",
+    );
+
+    let tools = vec!["cursor".to_string(), "copilot".to_string()];
+    let report = validate_glob_coverage(ctx.root(), &tools).unwrap();
+    let messages: Vec<_> = report.problems.iter().map(|p| p.message.as_str()).collect();
+
+    assert!(
+        report
+            .problems
+            .iter()
+            .all(|p| p.severity == Severity::Warning)
+    );
+    assert_eq!(
+        messages,
+        [
+            "Glob `**/*.tsx` matches no files in the working tree (cursor)",
+            "Rules `react`, `rust` and `rust-style` match exactly the same 2 file(s) in cursor; consider merging them",
+            "Rules `rust` and `rust-style` match exactly the same 2 file(s) in copilot; consider merging them",
+        ]
+    );
+    assert!(report.problems[0].location.ends_with("react.md:4:1"));
+}
+
+#[test]
+fn test_validate_fails_on_frontmatter_issues_only_when_strict() {
+    let ctx = TestContext::new().init_project();
+    ctx.create_agentsync_rule(
+        "typo",
+        "---\ntargets: [\"*\"]\ndescripton: Typo\n---\n\n# Typo\n",
+    );
+    let config = ctx.path("agentsync.json");
+
+    assert!(run_validate(None, Some(&config), false, false).is_ok());
+    assert!(matches!(
+        run_validate(None, Some(&config), false, true),
+        Err(AgentSyncError::ValidationFailed {
+            errors: 0,
+            warnings: 1
+        })
+    ));
+}